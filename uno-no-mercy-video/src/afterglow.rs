@@ -0,0 +1,84 @@
+//! Phosphor afterglow / motion-trail accumulation
+//!
+//! Every effect so far (`ScreenShake`, `Pulse`, `Particles`) is stateless --
+//! it recomputes everything from `time`/`seed` with nothing carried over
+//! between frames. `Afterglow` keeps an accumulator image across calls to
+//! `feed`, so bright pixels smear into CRT-style trails and light streaks
+//! instead of cutting cleanly frame to frame. Reached through
+//! `video::FrameAccumulator`, which `SceneManager` feeds scene 5's frames
+//! through for the chaos scene's motion trail.
+
+use image::{Rgba, RgbaImage};
+
+/// Per-channel decay added to the 0.49 base mix factor -- higher `p` means
+/// a longer-lived trail for that channel.
+#[derive(Debug, Clone, Copy)]
+pub struct Persistence {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl Persistence {
+    pub fn new(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Equal persistence on all three channels.
+    pub fn uniform(p: f32) -> Self {
+        Self::new(p, p, p)
+    }
+}
+
+impl Default for Persistence {
+    fn default() -> Self {
+        Self::uniform(0.35)
+    }
+}
+
+const DECAY: f32 = 1.25;
+const BLACK_THRESHOLD: f32 = 3.0;
+
+/// Holds the trail state across frames.
+pub struct Afterglow {
+    persistence: Persistence,
+    accumulator: Option<RgbaImage>,
+}
+
+impl Afterglow {
+    pub fn new(persistence: Persistence) -> Self {
+        Self { persistence, accumulator: None }
+    }
+
+    /// Blend `frame` into the stored accumulator and return the result,
+    /// which also becomes the new accumulator.
+    pub fn feed(&mut self, frame: &RgbaImage) -> RgbaImage {
+        let accumulate = self.accumulator.get_or_insert_with(|| frame.clone());
+        let mut result = RgbaImage::new(frame.width(), frame.height());
+
+        for (x, y, px) in frame.enumerate_pixels() {
+            let acc = accumulate.get_pixel(x, y);
+
+            if px[0] as f32 + px[1] as f32 + px[2] as f32 <= BLACK_THRESHOLD {
+                result.put_pixel(x, y, *px);
+                continue;
+            }
+
+            let mix = |c: usize, p: f32| -> u8 {
+                let factor = 0.49 + p;
+                let mixed = px[c] as f32 * (1.0 - factor) + acc[c] as f32 * factor;
+                (mixed - DECAY).max(0.0).clamp(0.0, 255.0) as u8
+            };
+
+            result.put_pixel(x, y, Rgba([
+                mix(0, self.persistence.r),
+                mix(1, self.persistence.g),
+                mix(2, self.persistence.b),
+                px[3],
+            ]));
+        }
+
+        *accumulate = result.clone();
+        result
+    }
+}
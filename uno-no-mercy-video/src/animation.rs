@@ -0,0 +1,265 @@
+//! Keyframe animation/tween subsystem
+//!
+//! This crate feeds a video, so most effects need a frame sequence, not a
+//! single still. `Lerp` interpolates an animated property (a position, an
+//! angle, a scale, an `Rgba<u8>`), `Easing` shapes the raw `t` before it's
+//! applied, and `Timeline` ties duration and fps together to yield one eased
+//! `t` per output frame. `CardAnimator` builds on top of those for the
+//! specific motions this crate needs -- deals, flips, glow pulses, fan
+//! spreads -- each returning `Vec<RgbaImage>` ready for encoding.
+
+use image::{Rgba, RgbaImage};
+
+use crate::blend::BlendMode;
+use crate::cards::{Card, CardRenderer};
+use crate::transform::{self, Affine2};
+
+/// A value that can be linearly interpolated toward another of the same type.
+pub trait Lerp {
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Lerp for f32 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Lerp for (f32, f32) {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        (self.0.lerp(&other.0, t), self.1.lerp(&other.1, t))
+    }
+}
+
+impl Lerp for Rgba<u8> {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        Rgba([
+            (self[0] as f32).lerp(&(other[0] as f32), t).round() as u8,
+            (self[1] as f32).lerp(&(other[1] as f32), t).round() as u8,
+            (self[2] as f32).lerp(&(other[2] as f32), t).round() as u8,
+            (self[3] as f32).lerp(&(other[3] as f32), t).round() as u8,
+        ])
+    }
+}
+
+/// An easing curve reshaping a raw `t \in [0, 1]` before it's used to
+/// interpolate a property.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    Linear,
+    EaseInOutCubic,
+    /// Overshoots past 1.0 before settling -- a bounce as a dealt card lands.
+    EaseOutBack,
+}
+
+impl Easing {
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::EaseOutBack => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (t - 1.0).powi(3) + c1 * (t - 1.0).powi(2)
+            }
+        }
+    }
+}
+
+/// Ties a duration and frame rate together, yielding one eased `t` per
+/// output frame.
+pub struct Timeline {
+    duration: f32,
+    fps: u32,
+    easing: Easing,
+}
+
+impl Timeline {
+    pub fn new(duration: f32, fps: u32, easing: Easing) -> Self {
+        Self { duration, fps, easing }
+    }
+
+    pub fn frame_count(&self) -> usize {
+        ((self.duration * self.fps as f32).round() as usize).max(1)
+    }
+
+    /// Yield this timeline's eased `t` for each frame, in order.
+    pub fn frames(&self) -> impl Iterator<Item = f32> + '_ {
+        let count = self.frame_count();
+        (0..count).map(move |i| {
+            let raw_t = if count > 1 { i as f32 / (count - 1) as f32 } else { 1.0 };
+            self.easing.apply(raw_t)
+        })
+    }
+
+    /// Interpolate `from` toward `to` once per frame.
+    pub fn tween<T: Lerp>(&self, from: &T, to: &T) -> Vec<T> {
+        self.frames().map(|t| from.lerp(to, t)).collect()
+    }
+}
+
+/// Generates keyframed motion sequences for cards, ready to hand to the
+/// encoder as a `Vec<RgbaImage>`.
+pub struct CardAnimator;
+
+impl CardAnimator {
+    /// Slide `card` from `from` to `to` on a `canvas_size` transparent
+    /// canvas, with an `EaseOutBack` bounce as it lands.
+    pub fn deal(card: &Card, card_size: (u32, u32), canvas_size: (u32, u32), from: (f32, f32), to: (f32, f32), duration: f32, fps: u32) -> Vec<RgbaImage> {
+        let timeline = Timeline::new(duration, fps, Easing::EaseOutBack);
+        let card_img = card.render(card_size.0, card_size.1);
+
+        timeline.frames().map(|t| {
+            let pos = from.lerp(&to, t);
+            let mut frame = RgbaImage::new(canvas_size.0, canvas_size.1);
+            let transform = Affine2::translation(pos.0, pos.1);
+            transform::composite_image_transformed(&mut frame, &card_img, transform, BlendMode::SrcOver);
+            frame
+        }).collect()
+    }
+
+    /// Flip `front` over to reveal `back`: horizontal scale tweens through
+    /// zero at the midpoint, where the faces swap.
+    pub fn flip(front: &Card, back: &Card, card_size: (u32, u32), canvas_size: (u32, u32), center: (f32, f32), duration: f32, fps: u32) -> Vec<RgbaImage> {
+        let timeline = Timeline::new(duration, fps, Easing::EaseInOutCubic);
+        let front_img = front.render(card_size.0, card_size.1);
+        let back_img = back.render(card_size.0, card_size.1);
+        let pivot = (card_size.0 as f32 / 2.0, card_size.1 as f32 / 2.0);
+
+        timeline.frames().map(|t| {
+            // Horizontal scale sweeps 1 -> 0 -> 1, hitting exactly 0 at the
+            // midpoint where the rendered face swaps.
+            let scale_x = (1.0 - 2.0 * t).abs();
+            let card_img = if t < 0.5 { &front_img } else { &back_img };
+
+            let mut frame = RgbaImage::new(canvas_size.0, canvas_size.1);
+            let transform = Affine2::around_pivot(Affine2::scale_xy(scale_x, 1.0), pivot, center);
+            transform::composite_image_transformed(&mut frame, card_img, transform, BlendMode::SrcOver);
+            frame
+        }).collect()
+    }
+
+    /// Pulse a card's glow between `min_glow` and `max_glow` and back, once
+    /// per `duration`.
+    pub fn glow_pulse(card: &Card, card_size: (u32, u32), glow_color: Rgba<u8>, min_glow: u32, max_glow: u32, duration: f32, fps: u32) -> Vec<RgbaImage> {
+        let timeline = Timeline::new(duration, fps, Easing::Linear);
+
+        timeline.frames().map(|t| {
+            // Ping-pong 0 -> 1 -> 0 across the timeline instead of a
+            // one-shot tween, since a pulse loops back on itself.
+            let triangle = 1.0 - (2.0 * t - 1.0).abs();
+            let glow_size = (min_glow as f32).lerp(&(max_glow as f32), triangle).round() as u32;
+            card.render_with_glow(card_size.0, card_size.1, glow_color, glow_size.max(1), BlendMode::Screen)
+        }).collect()
+    }
+
+    /// Spread `cards` from closed (spread angle 0) to `target_spread_angle`.
+    pub fn fan_spread(cards: &[Card], card_width: u32, card_height: u32, target_spread_angle: f32, duration: f32, fps: u32) -> Vec<RgbaImage> {
+        let timeline = Timeline::new(duration, fps, Easing::EaseInOutCubic);
+
+        timeline.frames().map(|t| {
+            let angle = 0.0_f32.lerp(&target_spread_angle, t);
+            CardRenderer::render_fan(cards, card_width, card_height, angle)
+        }).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cards::{CardColor, CardFactory};
+
+    #[test]
+    fn f32_lerp_is_exact_at_endpoints_and_midpoint() {
+        assert_eq!(0.0_f32.lerp(&10.0, 0.0), 0.0);
+        assert_eq!(0.0_f32.lerp(&10.0, 1.0), 10.0);
+        assert_eq!(0.0_f32.lerp(&10.0, 0.5), 5.0);
+    }
+
+    #[test]
+    fn rgba_lerp_interpolates_each_channel_independently() {
+        let a = Rgba([0, 0, 0, 0]);
+        let b = Rgba([255, 0, 100, 255]);
+        let mid = a.lerp(&b, 0.5);
+        assert_eq!(mid, Rgba([128, 0, 50, 128]));
+    }
+
+    #[test]
+    fn easing_linear_is_the_identity() {
+        assert_eq!(Easing::Linear.apply(0.37), 0.37);
+    }
+
+    #[test]
+    fn easing_in_out_cubic_hits_its_anchor_points() {
+        assert_eq!(Easing::EaseInOutCubic.apply(0.0), 0.0);
+        assert_eq!(Easing::EaseInOutCubic.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseInOutCubic.apply(1.0), 1.0);
+    }
+
+    #[test]
+    fn easing_out_back_overshoots_past_one_before_settling() {
+        assert_eq!(Easing::EaseOutBack.apply(0.0), 0.0);
+        assert_eq!(Easing::EaseOutBack.apply(1.0), 1.0);
+        let overshoot = (0..100).map(|i| Easing::EaseOutBack.apply(i as f32 / 100.0)).fold(0.0_f32, f32::max);
+        assert!(overshoot > 1.0, "EaseOutBack should overshoot 1.0 at some point, got max {overshoot}");
+    }
+
+    #[test]
+    fn easing_clamps_input_outside_zero_one() {
+        assert_eq!(Easing::Linear.apply(-5.0), 0.0);
+        assert_eq!(Easing::Linear.apply(5.0), 1.0);
+    }
+
+    #[test]
+    fn timeline_frame_count_matches_duration_and_fps() {
+        let timeline = Timeline::new(1.0, 30, Easing::Linear);
+        assert_eq!(timeline.frame_count(), 30);
+    }
+
+    #[test]
+    fn timeline_frame_count_is_never_zero() {
+        let timeline = Timeline::new(0.0, 30, Easing::Linear);
+        assert_eq!(timeline.frame_count(), 1);
+    }
+
+    #[test]
+    fn timeline_frames_start_at_zero_and_end_at_one() {
+        let timeline = Timeline::new(1.0, 10, Easing::Linear);
+        let frames: Vec<f32> = timeline.frames().collect();
+        assert_eq!(frames.first().copied(), Some(0.0));
+        assert_eq!(frames.last().copied(), Some(1.0));
+    }
+
+    #[test]
+    fn timeline_tween_produces_one_value_per_frame() {
+        let timeline = Timeline::new(1.0, 24, Easing::Linear);
+        let values = timeline.tween(&0.0, &100.0);
+        assert_eq!(values.len(), timeline.frame_count());
+        assert_eq!(values[0], 0.0);
+        assert_eq!(*values.last().unwrap(), 100.0);
+    }
+
+    #[test]
+    fn deal_produces_one_frame_per_timeline_frame_on_the_requested_canvas() {
+        let card = CardFactory::number(CardColor::Red, 5);
+        let frames = CardAnimator::deal(&card, (90, 135), (200, 300), (-100.0, 0.0), (50.0, 50.0), 0.5, 30);
+        assert_eq!(frames.len(), Timeline::new(0.5, 30, Easing::Linear).frame_count());
+        assert!(frames.iter().all(|f| f.width() == 200 && f.height() == 300));
+    }
+
+    #[test]
+    fn flip_produces_one_frame_per_timeline_frame_on_the_requested_canvas() {
+        let front = CardFactory::number(CardColor::Red, 1);
+        let back = CardFactory::number(CardColor::Blue, 2);
+        let frames = CardAnimator::flip(&front, &back, (90, 135), (200, 300), (100.0, 150.0), 1.0, 20);
+        assert_eq!(frames.len(), Timeline::new(1.0, 20, Easing::Linear).frame_count());
+        assert!(frames.iter().all(|f| f.width() == 200 && f.height() == 300));
+    }
+}
@@ -0,0 +1,126 @@
+//! Threshold bloom via a real separable Gaussian blur
+//!
+//! `effects::Glow::apply` splats an expanding circle of radius `r` around
+//! every opaque pixel, which is O(r^2 * pixels) and only works on
+//! pre-masked sprites with transparent backgrounds. `Bloom::apply` instead
+//! works on any rendered frame: a bright-pass keeps only the pixels whose
+//! luma clears `cutoff`, a two-pass separable Gaussian blur spreads that
+//! bright layer -- O(radius * pixels) instead of O(radius^2), so large
+//! glow radii stay practical on full-screen frames -- and the blurred
+//! bright layer is added back additively over the original. Reached through
+//! `video::FrameComposer::bloom`, which scene 1's title reveal now calls.
+
+use image::{Rgba, RgbaImage};
+
+use crate::blend::BlendMode;
+
+pub struct Bloom;
+
+impl Bloom {
+    /// Bloom `img`: keep pixels brighter than `cutoff`, blur them with a
+    /// Gaussian of `radius`/`sigma`, and add the result back over `img`
+    /// scaled by `intensity`.
+    pub fn apply(img: &RgbaImage, cutoff: f32, radius: u32, sigma: f32, intensity: f32) -> RgbaImage {
+        let bright = Self::bright_pass(img, cutoff);
+        let blurred = Self::gaussian_blur(&bright, radius, sigma);
+
+        let mut result = img.clone();
+        for (x, y, pixel) in blurred.enumerate_pixels() {
+            if pixel[3] == 0 {
+                continue;
+            }
+            let a = (pixel[3] as f32 * intensity).clamp(0.0, 255.0) as u8;
+            let glow = Rgba([pixel[0], pixel[1], pixel[2], a]);
+            let dest = result.get_pixel(x, y);
+            let blended = BlendMode::Add.blend_pixels(glow, *dest);
+            result.put_pixel(x, y, blended);
+        }
+        result
+    }
+
+    /// Keep only the portion of each pixel's luma above `cutoff`, rescaled
+    /// back up so the brightest pixels stay near full intensity.
+    fn bright_pass(img: &RgbaImage, cutoff: f32) -> RgbaImage {
+        let mut out = RgbaImage::new(img.width(), img.height());
+        let headroom = (1.0 - cutoff).max(0.0001);
+
+        for (x, y, pixel) in img.enumerate_pixels() {
+            let luma = 0.2126 * pixel[0] as f32 / 255.0
+                + 0.7152 * pixel[1] as f32 / 255.0
+                + 0.0722 * pixel[2] as f32 / 255.0;
+            let excess = (luma - cutoff).max(0.0);
+            if excess <= 0.0 {
+                continue;
+            }
+
+            let scale = excess / headroom;
+            out.put_pixel(x, y, Rgba([
+                (pixel[0] as f32 * scale).clamp(0.0, 255.0) as u8,
+                (pixel[1] as f32 * scale).clamp(0.0, 255.0) as u8,
+                (pixel[2] as f32 * scale).clamp(0.0, 255.0) as u8,
+                pixel[3],
+            ]));
+        }
+        out
+    }
+
+    /// Normalized Gaussian weights for taps `-radius..=radius`.
+    fn gaussian_weights(radius: u32, sigma: f32) -> Vec<f32> {
+        let r = radius as i32;
+        let mut weights: Vec<f32> = (-r..=r)
+            .map(|i| (-((i * i) as f32) / (2.0 * sigma * sigma)).exp())
+            .collect();
+        let sum: f32 = weights.iter().sum();
+        for w in weights.iter_mut() {
+            *w /= sum;
+        }
+        weights
+    }
+
+    /// Two-pass separable Gaussian blur: horizontal then vertical, each tap
+    /// clamped to the image edge.
+    fn gaussian_blur(img: &RgbaImage, radius: u32, sigma: f32) -> RgbaImage {
+        let width = img.width();
+        let height = img.height();
+        let weights = Self::gaussian_weights(radius, sigma);
+        let r = radius as i32;
+
+        let mut horiz = RgbaImage::new(width, height);
+        for y in 0..height {
+            for x in 0..width as i32 {
+                let mut sum = [0.0f32; 4];
+                for (k, dx) in (-r..=r).enumerate() {
+                    let sx = (x + dx).clamp(0, width as i32 - 1) as u32;
+                    let p = img.get_pixel(sx, y);
+                    let w = weights[k];
+                    for c in 0..4 {
+                        sum[c] += p[c] as f32 * w;
+                    }
+                }
+                horiz.put_pixel(x as u32, y, Rgba([sum[0] as u8, sum[1] as u8, sum[2] as u8, sum[3] as u8]));
+            }
+        }
+
+        let mut out = RgbaImage::new(width, height);
+        for x in 0..width {
+            for y in 0..height as i32 {
+                let mut sum = [0.0f32; 4];
+                for (k, dy) in (-r..=r).enumerate() {
+                    let sy = (y + dy).clamp(0, height as i32 - 1) as u32;
+                    let p = horiz.get_pixel(x, sy);
+                    let w = weights[k];
+                    for c in 0..4 {
+                        sum[c] += p[c] as f32 * w;
+                    }
+                }
+                out.put_pixel(x, y as u32, Rgba([
+                    sum[0].clamp(0.0, 255.0) as u8,
+                    sum[1].clamp(0.0, 255.0) as u8,
+                    sum[2].clamp(0.0, 255.0) as u8,
+                    sum[3].clamp(0.0, 255.0) as u8,
+                ]));
+            }
+        }
+        out
+    }
+}
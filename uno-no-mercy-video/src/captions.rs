@@ -0,0 +1,175 @@
+//! Karaoke-synced caption track generation.
+//!
+//! Instead of rasterizing caption text into every frame through
+//! `LowerThird`/`TitleCard`, this module lays each `project.toml` scene's
+//! narration out into timed lines and writes them as an Advanced SubStation
+//! Alpha (`.ass`) file, which `ffmpeg`'s `subtitles=` filter burns in at
+//! encode time as one vector-rendered, editable caption track.
+
+use crate::text::TextStyle;
+use image::Rgba;
+use std::path::Path;
+
+/// One timed line of dialogue to render as a karaoke caption.
+pub struct CaptionLine {
+    pub text: String,
+    pub start: f32,
+    pub end: f32,
+    pub style: String,
+}
+
+impl CaptionLine {
+    pub fn new(text: impl Into<String>, start: f32, end: f32) -> Self {
+        Self { text: text.into(), start, end, style: "Default".to_string() }
+    }
+
+    pub fn with_style(mut self, style: impl Into<String>) -> Self {
+        self.style = style.into();
+        self
+    }
+}
+
+/// One `[[scene]]`'s worth of narration to lay out as caption lines: the
+/// video-time window it owns and which ASS style to render it in.
+pub struct ScriptSegment<'a> {
+    pub start: f32,
+    pub end: f32,
+    pub text: &'a str,
+    pub style: &'a str,
+}
+
+/// A named `[V4+ Styles]` entry, so each `CaptionLine` can point at one of
+/// the crate's existing `TextStyle` presets by name.
+pub struct CaptionStyle {
+    pub name: &'static str,
+    pub style: TextStyle,
+}
+
+/// The crate's `TextStyle` presets, named for use as ASS style rows.
+pub fn default_caption_styles() -> Vec<CaptionStyle> {
+    vec![
+        CaptionStyle { name: "Default", style: TextStyle::white_with_black_outline() },
+        CaptionStyle { name: "RedBold", style: TextStyle::red_bold() },
+        CaptionStyle { name: "YellowImpact", style: TextStyle::yellow_impact() },
+        CaptionStyle { name: "BlueClean", style: TextStyle::blue_clean() },
+    ]
+}
+
+/// Split each segment's text into timed caption lines at sentence breaks,
+/// pacing every sentence's share of its segment's `[start, end)` window by
+/// word count, so captions land exactly on the scene timeline in
+/// `project.toml` instead of a flat words-per-second estimate.
+pub fn layout_segments(segments: &[ScriptSegment]) -> Vec<CaptionLine> {
+    let mut lines = Vec::new();
+
+    for segment in segments {
+        let sentences: Vec<&str> = segment.text
+            .split_inclusive(['.', '?', '!'])
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .collect();
+
+        let total_words: usize = sentences.iter()
+            .map(|s| s.split_whitespace().count().max(1))
+            .sum::<usize>()
+            .max(1);
+        let window = (segment.end - segment.start).max(0.0);
+
+        let mut t = segment.start;
+        for sentence in sentences {
+            let word_count = sentence.split_whitespace().count().max(1);
+            let duration = window * word_count as f32 / total_words as f32;
+            lines.push(CaptionLine::new(sentence, t, t + duration).with_style(segment.style));
+            t += duration;
+        }
+    }
+
+    lines
+}
+
+/// Write an Advanced SubStation Alpha (`.ass`) file: a `[Script Info]` block
+/// sized to the video's 1080x1920 canvas, one `[V4+ Styles]` row per entry
+/// in `styles`, and one `[Events]` Dialogue line per caption with
+/// `\k<centiseconds>` karaoke tags between each word so they light up in
+/// sync with `line.start`/`line.end`.
+pub fn write_ass_file(path: &Path, lines: &[CaptionLine], styles: &[CaptionStyle], font_size: f32) -> std::io::Result<()> {
+    let mut out = String::new();
+
+    out.push_str("[Script Info]\n");
+    out.push_str("ScriptType: v4.00+\n");
+    out.push_str("PlayResX: 1080\n");
+    out.push_str("PlayResY: 1920\n");
+    out.push_str("WrapStyle: 0\n");
+    out.push('\n');
+
+    out.push_str("[V4+ Styles]\n");
+    out.push_str("Format: Name, Fontname, Fontsize, PrimaryColour, SecondaryColour, OutlineColour, BackColour, Bold, Italic, Underline, StrikeOut, ScaleX, ScaleY, Spacing, Angle, BorderStyle, Outline, Shadow, Alignment, MarginL, MarginR, MarginV, Encoding\n");
+    for s in styles {
+        out.push_str(&style_line(s, font_size));
+    }
+    out.push('\n');
+
+    out.push_str("[Events]\n");
+    out.push_str("Format: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\n");
+    for line in lines {
+        out.push_str(&format!(
+            "Dialogue: 0,{start},{end},{style},,0,0,0,,{text}\n",
+            start = format_timestamp(line.start),
+            end = format_timestamp(line.end),
+            style = line.style,
+            text = karaoke_text(&line.text, line.end - line.start),
+        ));
+    }
+
+    std::fs::write(path, out)
+}
+
+fn style_line(s: &CaptionStyle, font_size: f32) -> String {
+    let primary = ass_color(s.style.color);
+    let outline_color = ass_color(s.style.outline_color.unwrap_or(Rgba([0, 0, 0, 255])));
+    let back_color = ass_color(s.style.shadow_color);
+    let outline = if s.style.outline_color.is_some() { s.style.outline_width as f32 } else { 0.0 };
+    let shadow = if s.style.shadow {
+        s.style.shadow_offset.0.unsigned_abs().max(s.style.shadow_offset.1.unsigned_abs()) as f32
+    } else {
+        0.0
+    };
+
+    format!(
+        "Style: {name},Roboto,{font_size},{primary},{primary},{outline_color},{back_color},0,0,0,0,100,100,0,0,1,{outline},{shadow},2,60,60,120,0\n",
+        name = s.name,
+    )
+}
+
+/// `{\k<cs>}word1 {\k<cs>}word2 ...` — an even per-word split of `duration`
+/// into centisecond karaoke tags, each placed before the word it times.
+fn karaoke_text(text: &str, duration: f32) -> String {
+    let words: Vec<&str> = text.split_whitespace().collect();
+    if words.is_empty() {
+        return String::new();
+    }
+
+    let per_word_cs = ((duration.max(0.0) / words.len() as f32) * 100.0).round().max(1.0) as u32;
+    words.iter()
+        .map(|w| format!("{{\\k{per_word_cs}}}{w}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// ASS colors are `&HAABBGGRR`, with alpha inverted (00 = opaque).
+fn ass_color(c: Rgba<u8>) -> String {
+    let alpha = 255 - c[3];
+    format!("&H{:02X}{:02X}{:02X}{:02X}", alpha, c[2], c[1], c[0])
+}
+
+/// Format seconds as the `H:MM:SS.cc` timestamp ASS Dialogue lines use.
+fn format_timestamp(seconds: f32) -> String {
+    let total_cs = (seconds.max(0.0) * 100.0).round() as u64;
+    let cs = total_cs % 100;
+    let total_secs = total_cs / 100;
+    let secs = total_secs % 60;
+    let total_mins = total_secs / 60;
+    let mins = total_mins % 60;
+    let hours = total_mins / 60;
+    format!("{hours}:{mins:02}:{secs:02}.{cs:02}")
+}
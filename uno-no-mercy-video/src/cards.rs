@@ -8,6 +8,11 @@ use image::{Rgba, RgbaImage};
 use imageproc::drawing::{draw_filled_rect_mut, draw_filled_ellipse_mut, draw_text_mut};
 use imageproc::rect::Rect;
 
+use crate::blend::BlendMode;
+use crate::brush::{Brush, Extend, GradientStop};
+use crate::shadow::{self, ShadowParams};
+use crate::transform::{self, Affine2};
+
 /// UNO card colors
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CardColor {
@@ -101,6 +106,7 @@ impl CardType {
 }
 
 /// Represents an UNO card
+#[derive(Clone)]
 pub struct Card {
     pub color: CardColor,
     pub card_type: CardType,
@@ -111,13 +117,24 @@ impl Card {
         Self { color, card_type }
     }
 
-    /// Render the card as an image with professional styling
+    /// Render the card as an image with professional styling. Wild cards
+    /// get a rainbow radial fill in place of their (otherwise black) flat
+    /// gradient; everything else uses the usual two-tone diagonal ramp.
     pub fn render(&self, width: u32, height: u32) -> RgbaImage {
+        let gradient_brush = matches!(self.color, CardColor::Wild).then(|| Self::wild_rainbow_brush(width, height));
+        self.render_with_brush(width, height, gradient_brush.as_ref(), None, None)
+    }
+
+    /// Same as `render`, but `gradient_brush`/`oval_highlight_brush` override
+    /// the card body's and the diagonal oval's built-in gradients instead of
+    /// their default diagonal/radial ramps, and `shadow` overrides the drop
+    /// shadow's offset/blur/color/opacity instead of `ShadowParams::default`.
+    pub fn render_with_brush(&self, width: u32, height: u32, gradient_brush: Option<&Brush>, oval_highlight_brush: Option<&Brush>, shadow: Option<ShadowParams>) -> RgbaImage {
         let mut img = RgbaImage::new(width, height);
         let corner_radius = (width.min(height) as f32 * 0.12) as i32;
 
         // Draw drop shadow first
-        self.draw_shadow(&mut img, width, height, corner_radius);
+        self.draw_shadow(&mut img, width, height, corner_radius, &shadow.unwrap_or_default());
 
         // Draw card background with rounded corners
         self.draw_rounded_rect(&mut img, 4, 4, width - 8, height - 8, corner_radius,
@@ -127,10 +144,10 @@ impl Card {
         let border = 8;
         self.draw_card_gradient(&mut img, border, border,
             width - border as u32 * 2, height - border as u32 * 2,
-            corner_radius - 4);
+            corner_radius - 4, gradient_brush);
 
         // Draw the diagonal white oval (UNO signature style)
-        self.draw_diagonal_oval(&mut img, width, height);
+        self.draw_diagonal_oval(&mut img, width, height, oval_highlight_brush);
 
         // Draw center symbol/text
         self.draw_center_symbol(&mut img, width, height);
@@ -141,26 +158,69 @@ impl Card {
         img
     }
 
-    fn draw_shadow(&self, img: &mut RgbaImage, width: u32, height: u32, corner_radius: i32) {
-        let shadow_offset = 6;
-        let shadow_blur = 8;
-
-        for blur in 0..shadow_blur {
-            let alpha = ((shadow_blur - blur) as f32 / shadow_blur as f32 * 80.0) as u8;
-            let expand = blur as i32;
-            self.draw_rounded_rect(
-                img,
-                shadow_offset - expand,
-                shadow_offset - expand,
-                width + expand as u32 * 2 - 8,
-                height + expand as u32 * 2 - 8,
-                corner_radius + expand,
-                Rgba([0, 0, 0, alpha]),
-            );
+    /// A multi-stop rainbow radial fill for Wild cards, cycling through the
+    /// four card colors and back via `Extend::Reflect`.
+    fn wild_rainbow_brush(width: u32, height: u32) -> Brush {
+        let center = (width as f32 / 2.0, height as f32 / 2.0);
+        let radius = width.max(height) as f32 * 0.6;
+
+        Brush::radial(center, 0.0, center, radius, vec![
+            GradientStop::new(0.0, CardColor::Red.to_rgba()),
+            GradientStop::new(0.25, CardColor::Yellow.to_rgba()),
+            GradientStop::new(0.5, CardColor::Green.to_rgba()),
+            GradientStop::new(0.75, CardColor::Blue.to_rgba()),
+            GradientStop::new(1.0, CardColor::Red.to_rgba()),
+        ], Extend::Reflect)
+    }
+
+    /// Blur the card body's silhouette into a mask and composite it, tinted
+    /// and offset per `params`, underneath the card -- a real soft shadow
+    /// instead of a handful of stacked, hard-edged expanding rects.
+    fn draw_shadow(&self, img: &mut RgbaImage, width: u32, height: u32, corner_radius: i32, params: &ShadowParams) {
+        // Same footprint as the white border rect drawn right after this:
+        // a rounded rect inset by 4px on every side.
+        let body_x = 4;
+        let body_y = 4;
+        let body_w = width as i32 - 8;
+        let body_h = height as i32 - 8;
+
+        let mask = shadow::render_mask(width, height, |x, y| {
+            let px = x - body_x;
+            let py = y - body_y;
+            if px < 0 || py < 0 || px >= body_w || py >= body_h {
+                0.0
+            } else {
+                self.rounded_rect_coverage(px, py, body_w, body_h, corner_radius)
+            }
+        });
+        let blurred = shadow::blur_mask(&mask, width, height, params.blur_radius);
+        let shadow_img = shadow::tint_mask(&blurred, width, height, params);
+
+        for (sx, sy, pixel) in shadow_img.enumerate_pixels() {
+            if pixel[3] == 0 {
+                continue;
+            }
+
+            let dx = sx as i32 + params.offset_x;
+            let dy = sy as i32 + params.offset_y;
+            if dx < 0 || dy < 0 || dx as u32 >= img.width() || dy as u32 >= img.height() {
+                continue;
+            }
+
+            let dest = img.get_pixel(dx as u32, dy as u32);
+            let blended = BlendMode::SrcOver.blend_pixels(*pixel, *dest);
+            img.put_pixel(dx as u32, dy as u32, blended);
         }
     }
 
     fn draw_rounded_rect(&self, img: &mut RgbaImage, x: i32, y: i32, w: u32, h: u32, radius: i32, color: Rgba<u8>) {
+        self.draw_rounded_rect_blended(img, x, y, w, h, radius, color, BlendMode::SrcOver);
+    }
+
+    /// Same as `draw_rounded_rect`, but composited with `mode` instead of
+    /// plain source-over.
+    #[allow(clippy::too_many_arguments)]
+    fn draw_rounded_rect_blended(&self, img: &mut RgbaImage, x: i32, y: i32, w: u32, h: u32, radius: i32, color: Rgba<u8>, mode: BlendMode) {
         let w = w as i32;
         let h = h as i32;
 
@@ -173,66 +233,53 @@ impl Card {
                     continue;
                 }
 
-                // Check if point is within rounded rectangle
-                let in_corner = self.point_in_rounded_rect(px, py, w, h, radius);
-                if in_corner {
+                let coverage = self.rounded_rect_coverage(px, py, w, h, radius);
+                if coverage > 0.0 {
+                    let source = Rgba([color[0], color[1], color[2], (color[3] as f32 * coverage) as u8]);
                     let dest = img.get_pixel(dx as u32, dy as u32);
-                    let blended = Self::blend_pixels(color, *dest);
+                    let blended = mode.blend_pixels(source, *dest);
                     img.put_pixel(dx as u32, dy as u32, blended);
                 }
             }
         }
     }
 
-    fn point_in_rounded_rect(&self, px: i32, py: i32, w: i32, h: i32, radius: i32) -> bool {
-        // Check corners
-        let corners = [
-            (radius, radius),                    // Top-left
-            (w - radius - 1, radius),            // Top-right
-            (radius, h - radius - 1),            // Bottom-left
-            (w - radius - 1, h - radius - 1),    // Bottom-right
-        ];
-
-        for (cx, cy) in corners {
-            let in_corner_zone = (px < radius && py < radius) ||
-                                 (px >= w - radius && py < radius) ||
-                                 (px < radius && py >= h - radius) ||
-                                 (px >= w - radius && py >= h - radius);
-
-            if in_corner_zone {
-                let dx = (px - cx).abs();
-                let dy = (py - cy).abs();
-                let dist = ((dx * dx + dy * dy) as f32).sqrt();
-                if dist > radius as f32 {
-                    return false;
-                }
-            }
-        }
+    /// Fractional coverage (0.0-1.0) of a rounded rectangle at `(px, py)`:
+    /// 1.0 away from the corners, and a signed-distance falloff
+    /// `clamp(radius + 0.5 - d, 0.0, 1.0)` from the nearest corner-circle
+    /// center `d` pixels away once inside that corner's square zone, so
+    /// the arc's boundary blends instead of cutting off as a hard edge.
+    fn rounded_rect_coverage(&self, px: i32, py: i32, w: i32, h: i32, radius: i32) -> f32 {
+        let in_corner_zone = (px < radius && py < radius) ||
+                             (px >= w - radius && py < radius) ||
+                             (px < radius && py >= h - radius) ||
+                             (px >= w - radius && py >= h - radius);
 
-        true
-    }
-
-    fn blend_pixels(src: Rgba<u8>, dest: Rgba<u8>) -> Rgba<u8> {
-        let src_a = src[3] as f32 / 255.0;
-        let dest_a = dest[3] as f32 / 255.0;
-        let out_a = src_a + dest_a * (1.0 - src_a);
-
-        if out_a == 0.0 {
-            return Rgba([0, 0, 0, 0]);
+        if !in_corner_zone {
+            return 1.0;
         }
 
-        let r = (src[0] as f32 * src_a + dest[0] as f32 * dest_a * (1.0 - src_a)) / out_a;
-        let g = (src[1] as f32 * src_a + dest[1] as f32 * dest_a * (1.0 - src_a)) / out_a;
-        let b = (src[2] as f32 * src_a + dest[2] as f32 * dest_a * (1.0 - src_a)) / out_a;
+        let cx = if px < radius { radius } else { w - radius - 1 };
+        let cy = if py < radius { radius } else { h - radius - 1 };
+
+        let dx = (px - cx) as f32;
+        let dy = (py - cy) as f32;
+        let dist = (dx * dx + dy * dy).sqrt();
 
-        Rgba([r as u8, g as u8, b as u8, (out_a * 255.0) as u8])
+        (radius as f32 + 0.5 - dist).clamp(0.0, 1.0)
     }
 
-    fn draw_card_gradient(&self, img: &mut RgbaImage, x: i32, y: i32, w: u32, h: u32, radius: i32) {
-        let base = self.color.to_rgba();
+    fn draw_card_gradient(&self, img: &mut RgbaImage, x: i32, y: i32, w: u32, h: u32, radius: i32, brush: Option<&Brush>) {
         let dark = self.color.to_dark();
         let light = self.color.to_light();
 
+        // Default: top-left (light) to bottom-right (dark) diagonal ramp.
+        let default_brush = Brush::linear((0.0, 0.0), (w as f32, h as f32), vec![
+            GradientStop::new(0.0, light),
+            GradientStop::new(1.0, dark),
+        ], Extend::Pad);
+        let brush = brush.unwrap_or(&default_brush);
+
         for py in 0..h as i32 {
             for px in 0..w as i32 {
                 let dx = x + px;
@@ -242,12 +289,12 @@ impl Card {
                     continue;
                 }
 
-                if !self.point_in_rounded_rect(px, py, w as i32, h as i32, radius) {
+                let coverage = self.rounded_rect_coverage(px, py, w as i32, h as i32, radius);
+                if coverage <= 0.0 {
                     continue;
                 }
 
-                // Gradient from top-left (light) to bottom-right (dark)
-                let t = (px as f32 / w as f32 + py as f32 / h as f32) / 2.0;
+                let sampled = brush.sample(px as f32, py as f32);
 
                 // Add some shine at top
                 let shine = if py < h as i32 / 4 {
@@ -256,25 +303,19 @@ impl Card {
                     0.0
                 };
 
-                let r = Self::lerp(light[0] as f32, dark[0] as f32, t) + shine * 50.0;
-                let g = Self::lerp(light[1] as f32, dark[1] as f32, t) + shine * 50.0;
-                let b = Self::lerp(light[2] as f32, dark[2] as f32, t) + shine * 50.0;
+                let r = sampled[0] as f32 + shine * 50.0;
+                let g = sampled[1] as f32 + shine * 50.0;
+                let b = sampled[2] as f32 + shine * 50.0;
 
-                img.put_pixel(dx as u32, dy as u32, Rgba([
-                    r.min(255.0) as u8,
-                    g.min(255.0) as u8,
-                    b.min(255.0) as u8,
-                    255
-                ]));
+                let source = Rgba([r.min(255.0) as u8, g.min(255.0) as u8, b.min(255.0) as u8, (255.0 * coverage) as u8]);
+                let dest = img.get_pixel(dx as u32, dy as u32);
+                let blended = BlendMode::SrcOver.blend_pixels(source, *dest);
+                img.put_pixel(dx as u32, dy as u32, blended);
             }
         }
     }
 
-    fn lerp(a: f32, b: f32, t: f32) -> f32 {
-        a + (b - a) * t
-    }
-
-    fn draw_diagonal_oval(&self, img: &mut RgbaImage, width: u32, height: u32) {
+    fn draw_diagonal_oval(&self, img: &mut RgbaImage, width: u32, height: u32, brush: Option<&Brush>) {
         let cx = width as f32 / 2.0;
         let cy = height as f32 / 2.0;
         let oval_a = width as f32 * 0.38;  // Semi-major axis
@@ -284,6 +325,13 @@ impl Card {
         let cos_a = angle.cos();
         let sin_a = angle.sin();
 
+        // Default: a soft white radial highlight, brightest at the center.
+        let default_brush = Brush::radial((cx, cy), 0.0, (cx, cy), oval_a.max(oval_b), vec![
+            GradientStop::new(0.0, Rgba([255, 255, 255, 255])),
+            GradientStop::new(1.0, Rgba([240, 240, 240, 255])),
+        ], Extend::Pad);
+        let brush = brush.unwrap_or(&default_brush);
+
         for y in 0..height {
             for x in 0..width {
                 // Transform to oval-centered coordinates
@@ -294,21 +342,28 @@ impl Card {
                 let rx = dx * cos_a + dy * sin_a;
                 let ry = -dx * sin_a + dy * cos_a;
 
-                // Check if inside ellipse
-                let dist = (rx / oval_a).powi(2) + (ry / oval_b).powi(2);
-
-                if dist <= 1.0 {
-                    // Gradient from center to edge for subtle 3D effect
-                    let edge_dist = 1.0 - dist;
-                    let brightness = 240 + (15.0 * edge_dist) as u8;
-                    img.put_pixel(x, y, Rgba([brightness, brightness, brightness, 255]));
-                } else if dist <= 1.08 {
-                    // Subtle shadow at edge
-                    let alpha = ((1.08 - dist) / 0.08 * 100.0) as u8;
-                    let current = img.get_pixel(x, y);
-                    let blended = Self::blend_pixels(Rgba([200, 200, 200, alpha]), *current);
-                    img.put_pixel(x, y, blended);
+                // Implicit ellipse value: < 1.0 inside, > 1.0 outside.
+                let value = (rx / oval_a).powi(2) + (ry / oval_b).powi(2);
+
+                // Convert the `value <= 1.0` cutoff into a coverage ramp by
+                // dividing the signed distance from the level set by the
+                // gradient magnitude of `value`, i.e. how fast it changes
+                // per pixel here -- the same trick as `shapes`' circle AA,
+                // just for an ellipse's implicit function instead of a
+                // literal Euclidean distance.
+                let d_value_dx = 2.0 * rx / oval_a.powi(2) * cos_a - 2.0 * ry / oval_b.powi(2) * sin_a;
+                let d_value_dy = 2.0 * rx / oval_a.powi(2) * sin_a + 2.0 * ry / oval_b.powi(2) * cos_a;
+                let gradient_mag = (d_value_dx.powi(2) + d_value_dy.powi(2)).sqrt().max(1e-6);
+
+                let coverage = (0.5 - (value - 1.0) / gradient_mag).clamp(0.0, 1.0);
+                if coverage <= 0.0 {
+                    continue;
                 }
+
+                let sampled = brush.sample(x as f32, y as f32);
+                let current = img.get_pixel(x, y);
+                let blended = BlendMode::SrcOver.blend_pixels(Rgba([sampled[0], sampled[1], sampled[2], (255.0 * coverage) as u8]), *current);
+                img.put_pixel(x, y, blended);
             }
         }
     }
@@ -394,26 +449,40 @@ impl Card {
         draw_text_mut(img, text_color, br_x, br_y, scale, &font, &text);
     }
 
-    /// Render a card with a glow effect
-    pub fn render_with_glow(&self, width: u32, height: u32, glow_color: Rgba<u8>, glow_size: u32) -> RgbaImage {
+    /// Render a card with a glow effect, blended into whatever's behind it
+    /// with `mode` (e.g. `BlendMode::Screen`/`Add` for a realistic light
+    /// bloom instead of a flat overlay). Reached through
+    /// `animation::CardAnimator::glow_pulse`, which scene 4's +4 reveal now
+    /// animates with.
+    pub fn render_with_glow(&self, width: u32, height: u32, glow_color: Rgba<u8>, glow_size: u32, mode: BlendMode) -> RgbaImage {
         let total_width = width + glow_size * 2;
         let total_height = height + glow_size * 2;
         let mut img = RgbaImage::new(total_width, total_height);
 
-        // Draw glow with gradient falloff
+        // Blur the card's silhouette mask for the glow's falloff, the same
+        // way `draw_shadow` builds a soft shadow, instead of stacking
+        // expanding rects.
         let corner_radius = (width.min(height) as f32 * 0.12) as i32;
-        for i in 0..glow_size {
-            let alpha = ((glow_size - i) as f32 / glow_size as f32).powi(2) * 180.0;
-            let glow = Rgba([glow_color[0], glow_color[1], glow_color[2], alpha as u8]);
-            self.draw_rounded_rect(
-                &mut img,
-                i as i32,
-                i as i32,
-                total_width - i * 2,
-                total_height - i * 2,
-                corner_radius + (glow_size - i) as i32,
-                glow,
-            );
+        let mask = shadow::render_mask(total_width, total_height, |x, y| {
+            let px = x - glow_size as i32;
+            let py = y - glow_size as i32;
+            if px < 0 || py < 0 || px >= width as i32 || py >= height as i32 {
+                0.0
+            } else {
+                self.rounded_rect_coverage(px, py, width as i32, height as i32, corner_radius)
+            }
+        });
+        let blurred = shadow::blur_mask(&mask, total_width, total_height, glow_size);
+        let glow_params = ShadowParams::new(0, 0, glow_size, glow_color, 0.9);
+        let glow_img = shadow::tint_mask(&blurred, total_width, total_height, &glow_params);
+
+        for (x, y, pixel) in glow_img.enumerate_pixels() {
+            if pixel[3] == 0 {
+                continue;
+            }
+            let dest = img.get_pixel(x, y);
+            let blended = mode.blend_pixels(*pixel, *dest);
+            img.put_pixel(x, y, blended);
         }
 
         // Draw card on top
@@ -424,7 +493,7 @@ impl Card {
                 let dest_y = y + glow_size;
                 if dest_x < total_width && dest_y < total_height {
                     let dest = img.get_pixel(dest_x, dest_y);
-                    let blended = Self::blend_pixels(*pixel, *dest);
+                    let blended = BlendMode::SrcOver.blend_pixels(*pixel, *dest);
                     img.put_pixel(dest_x, dest_y, blended);
                 }
             }
@@ -504,13 +573,16 @@ impl CardRenderer {
             let x = (i as i32 * offset.abs()) as u32;
             let y = (i as i32 * offset.abs()) as u32;
 
-            Self::composite_image(&mut img, &card_img, x as i32, y as i32);
+            Self::composite_image(&mut img, &card_img, x as i32, y as i32, BlendMode::SrcOver);
         }
 
         img
     }
 
-    /// Render cards in a fan arrangement
+    /// Render cards in a fan arrangement, each rotated around its own
+    /// bottom-center pivot, with the pivots spaced along a shared baseline,
+    /// so the fan actually spreads instead of just sliding cards sideways. Reached through `animation::CardAnimator::fan_spread`,
+    /// which scene 2's doomed hand now animates with.
     pub fn render_fan(cards: &[Card], card_width: u32, card_height: u32, spread_angle: f32) -> RgbaImage {
         let num_cards = cards.len();
         if num_cards == 0 {
@@ -526,24 +598,23 @@ impl CardRenderer {
 
         let start_angle = -spread_angle / 2.0;
         let angle_step = if num_cards > 1 { spread_angle / (num_cards - 1) as f32 } else { 0.0 };
+        let pivot = (card_width as f32 / 2.0, card_height as f32);
 
         for (i, card) in cards.iter().enumerate() {
             let angle = start_angle + angle_step * i as f32;
             let card_img = card.render(card_width, card_height);
 
-            // Simple positioning (rotation would need more complex implementation)
-            let offset_x = (angle.sin() * card_width as f32 * 0.8) as i32;
-            let x = center_x as i32 - card_width as i32 / 2 + offset_x;
-            let y = center_y as i32 - card_height as i32;
+            let dest_pivot = (center_x + angle.sin() * card_width as f32 * 0.8, center_y);
+            let transform = Affine2::around_pivot(Affine2::rotation_scale(angle, 1.0), pivot, dest_pivot);
 
-            Self::composite_image(&mut img, &card_img, x, y);
+            transform::composite_image_transformed(&mut img, &card_img, transform, BlendMode::SrcOver);
         }
 
         img
     }
 
-    /// Composite one image onto another at given position with alpha blending
-    fn composite_image(dest: &mut RgbaImage, src: &RgbaImage, x: i32, y: i32) {
+    /// Composite one image onto another at given position, blended with `mode`.
+    fn composite_image(dest: &mut RgbaImage, src: &RgbaImage, x: i32, y: i32, mode: BlendMode) {
         for (sx, sy, pixel) in src.enumerate_pixels() {
             let dx = x + sx as i32;
             let dy = y + sy as i32;
@@ -551,7 +622,7 @@ impl CardRenderer {
             if dx >= 0 && dy >= 0 && (dx as u32) < dest.width() && (dy as u32) < dest.height() {
                 if pixel[3] > 0 {
                     let dest_pixel = dest.get_pixel(dx as u32, dy as u32);
-                    let blended = Card::blend_pixels(*pixel, *dest_pixel);
+                    let blended = mode.blend_pixels(*pixel, *dest_pixel);
                     dest.put_pixel(dx as u32, dy as u32, blended);
                 }
             }
@@ -585,7 +656,13 @@ impl CardRenderer {
             let x = rng.gen_range(0..(canvas_width.saturating_sub(small_width)).max(1)) as i32;
             let y = rng.gen_range(0..(canvas_height.saturating_sub(small_height)).max(1)) as i32;
 
-            Self::composite_image(&mut img, &card_img, x, y);
+            // Random tilt so scattered cards don't all sit dead flat
+            let angle = rng.gen_range(-0.6..0.6);
+            let pivot = (small_width as f32 / 2.0, small_height as f32 / 2.0);
+            let dest_pivot = (x as f32 + pivot.0, y as f32 + pivot.1);
+            let transform = Affine2::around_pivot(Affine2::rotation_scale(angle, 1.0), pivot, dest_pivot);
+
+            transform::composite_image_transformed(&mut img, &card_img, transform, BlendMode::SrcOver);
         }
 
         img
@@ -4,6 +4,120 @@
 //! and smooth gradients for professional animated quality.
 
 use image::{Rgba, RgbaImage};
+use imageproc::drawing::draw_filled_rect_mut;
+use imageproc::rect::Rect;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// (De)serializes `image::Rgba<u8>` as a plain `[u8; 4]`, since `Rgba` itself
+/// doesn't derive serde traits.
+mod serde_rgba {
+    use image::Rgba;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S: Serializer>(color: &Rgba<u8>, s: S) -> Result<S::Ok, S::Error> {
+        color.0.serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Rgba<u8>, D::Error> {
+        Ok(Rgba(<[u8; 4]>::deserialize(d)?))
+    }
+}
+
+/// One cubic Bézier segment of a closed path, in absolute image coordinates;
+/// it starts at the previous segment's endpoint (or the path's start point
+/// for the first segment in a loop).
+#[derive(Debug, Clone, Copy)]
+struct BezierSegment {
+    control1: (f32, f32),
+    control2: (f32, f32),
+    end: (f32, f32),
+}
+
+/// A scalar signed-distance field over a bounding box of image pixels, used
+/// to merge a handful of ellipse primitives (e.g. hair spikes) into one
+/// continuous blobby silhouette instead of drawing each one separately.
+/// Values are normalized ellipse distance: negative inside, zero on the
+/// boundary, positive outside.
+struct FieldBuffer {
+    origin_x: i32,
+    origin_y: i32,
+    width: u32,
+    height: u32,
+    values: Vec<f32>,
+}
+
+impl FieldBuffer {
+    /// Allocate a field covering `(min_x, min_y)..(max_x, max_y)`, clamped to
+    /// the image bounds, with every cell initialized far outside any shape.
+    fn new(min_x: f32, min_y: f32, max_x: f32, max_y: f32, img_width: u32, img_height: u32) -> Self {
+        let origin_x = min_x.floor().max(0.0) as i32;
+        let origin_y = min_y.floor().max(0.0) as i32;
+        let end_x = (max_x.ceil() as i32).clamp(origin_x, img_width as i32);
+        let end_y = (max_y.ceil() as i32).clamp(origin_y, img_height as i32);
+        let width = (end_x - origin_x) as u32;
+        let height = (end_y - origin_y) as u32;
+
+        Self {
+            origin_x,
+            origin_y,
+            width,
+            height,
+            values: vec![1.0; (width * height) as usize],
+        }
+    }
+
+    fn index(&self, x: i32, y: i32) -> Option<usize> {
+        let lx = x - self.origin_x;
+        let ly = y - self.origin_y;
+        if lx < 0 || ly < 0 || lx as u32 >= self.width || ly as u32 >= self.height {
+            None
+        } else {
+            Some(ly as usize * self.width as usize + lx as usize)
+        }
+    }
+
+    /// Field value at `(x, y)`, or a safely-outside default if out of bounds
+    /// (so gradient samples at the field's edge don't wrap or panic).
+    fn get(&self, x: i32, y: i32) -> f32 {
+        self.index(x, y).map(|i| self.values[i]).unwrap_or(1.0)
+    }
+
+    /// Smooth-minimum blend of the field's current value against `value`,
+    /// using Inigo Quilez's polynomial `smin` — the same merge used to melt
+    /// metaballs together in raymarched SDF scenes. `k` is the blend radius
+    /// in the same normalized ellipse-distance units as the field itself;
+    /// `k = 0` degenerates to a hard, unblended minimum.
+    fn smin(a: f32, b: f32, k: f32) -> f32 {
+        if k <= 0.0 {
+            return a.min(b);
+        }
+        let h = (k - (a - b).abs()).max(0.0) / k;
+        a.min(b) - h * h * k * 0.25
+    }
+
+    /// Accumulate one ellipse primitive into the field.
+    fn add_ellipse(&mut self, cx: f32, cy: f32, rx: f32, ry: f32, blend: f32) {
+        let pad = rx.max(ry) * blend + 2.0;
+        let x_start = ((cx - rx - pad).floor() as i32).max(self.origin_x);
+        let x_end = ((cx + rx + pad).ceil() as i32).min(self.origin_x + self.width as i32);
+        let y_start = ((cy - ry - pad).floor() as i32).max(self.origin_y);
+        let y_end = ((cy + ry + pad).ceil() as i32).min(self.origin_y + self.height as i32);
+
+        for y in y_start..y_end {
+            for x in x_start..x_end {
+                let dx = (x as f32 + 0.5 - cx) / rx;
+                let dy = (y as f32 + 0.5 - cy) / ry;
+                let dist = (dx * dx + dy * dy).sqrt() - 1.0;
+
+                if let Some(i) = self.index(x, y) {
+                    self.values[i] = Self::smin(self.values[i], dist, blend);
+                }
+            }
+        }
+    }
+}
 
 /// Character expressions
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -16,26 +130,165 @@ pub enum Expression {
     Whispering,
 }
 
+/// Continuous blend-shape parameters for one facial expression, so any two
+/// expressions can be linearly interpolated into the frames in between
+/// (the same idea expressive avatar shaders use for morph targets).
+/// `brow_height` is how far above the eye line each eyebrow sits.
+#[derive(Debug, Clone, Copy)]
+pub struct FaceParams {
+    pub eye_width: f32,
+    pub eye_height: f32,
+    pub pupil_offset: (f32, f32),
+    pub brow_angle: (f32, f32),
+    pub brow_height: (f32, f32),
+    pub mouth_width: f32,
+    pub mouth_height: f32,
+    pub mouth_open: f32,
+    pub mouth_offset: (f32, f32),
+    pub show_teeth: f32,
+    pub show_tongue: f32,
+    pub spiral_eyes: f32,
+}
+
+impl FaceParams {
+    pub const NEUTRAL: Self = Self {
+        eye_width: 26.0, eye_height: 32.0, pupil_offset: (0.0, 0.0),
+        brow_angle: (0.0, 0.0), brow_height: (42.0, 42.0),
+        mouth_width: 32.0, mouth_height: 16.0, mouth_open: 0.0, mouth_offset: (0.0, 58.0),
+        show_teeth: 1.0, show_tongue: 0.0, spiral_eyes: 0.0,
+    };
+
+    pub const SHOCKED: Self = Self {
+        eye_width: 34.0, eye_height: 42.0, pupil_offset: (0.0, -4.0),
+        brow_angle: (-0.35, 0.35), brow_height: (58.0, 58.0),
+        mouth_width: 32.0, mouth_height: 38.0, mouth_open: 1.0, mouth_offset: (0.0, 62.0),
+        show_teeth: 1.0, show_tongue: 0.0, spiral_eyes: 0.0,
+    };
+
+    pub const SERIOUS: Self = Self {
+        eye_width: 30.0, eye_height: 20.0, pupil_offset: (0.0, 0.0),
+        brow_angle: (0.45, -0.45), brow_height: (32.0, 32.0),
+        mouth_width: 28.0, mouth_height: 5.0, mouth_open: 0.0, mouth_offset: (0.0, 58.0),
+        show_teeth: 0.0, show_tongue: 0.0, spiral_eyes: 0.0,
+    };
+
+    pub const MISCHIEVOUS: Self = Self {
+        eye_width: 26.0, eye_height: 24.0, pupil_offset: (5.0, 0.0),
+        brow_angle: (0.25, -0.3), brow_height: (38.0, 45.0),
+        mouth_width: 34.0, mouth_height: 14.0, mouth_open: 0.4, mouth_offset: (10.0, 58.0),
+        show_teeth: 1.0, show_tongue: 0.0, spiral_eyes: 0.0,
+    };
+
+    pub const MIND_BLOWN: Self = Self {
+        eye_width: 38.0, eye_height: 48.0, pupil_offset: (0.0, 0.0),
+        brow_angle: (-0.4, 0.4), brow_height: (65.0, 65.0),
+        mouth_width: 42.0, mouth_height: 48.0, mouth_open: 1.0, mouth_offset: (0.0, 68.0),
+        show_teeth: 1.0, show_tongue: 1.0, spiral_eyes: 1.0,
+    };
+
+    pub const WHISPERING: Self = Self {
+        eye_width: 24.0, eye_height: 28.0, pupil_offset: (9.0, 0.0),
+        brow_angle: (-0.12, 0.12), brow_height: (40.0, 40.0),
+        mouth_width: 14.0, mouth_height: 12.0, mouth_open: 0.0, mouth_offset: (0.0, 58.0),
+        show_teeth: 0.0, show_tongue: 0.0, spiral_eyes: 0.0,
+    };
+
+    pub fn for_expression(expression: Expression) -> Self {
+        match expression {
+            Expression::Neutral => Self::NEUTRAL,
+            Expression::Shocked => Self::SHOCKED,
+            Expression::Serious => Self::SERIOUS,
+            Expression::Mischievous => Self::MISCHIEVOUS,
+            Expression::MindBlown => Self::MIND_BLOWN,
+            Expression::Whispering => Self::WHISPERING,
+        }
+    }
+
+    /// Linearly interpolate every field toward `other` by `t` (0 = self,
+    /// 1 = other); `show_teeth`/`show_tongue`/`spiral_eyes` are plain floats
+    /// so this doubles as their alpha crossfade.
+    pub fn lerp(a: &Self, b: &Self, t: f32) -> Self {
+        let lerp = |x: f32, y: f32| x + (y - x) * t;
+        let lerp2 = |x: (f32, f32), y: (f32, f32)| (lerp(x.0, y.0), lerp(x.1, y.1));
+        Self {
+            eye_width: lerp(a.eye_width, b.eye_width),
+            eye_height: lerp(a.eye_height, b.eye_height),
+            pupil_offset: lerp2(a.pupil_offset, b.pupil_offset),
+            brow_angle: lerp2(a.brow_angle, b.brow_angle),
+            brow_height: lerp2(a.brow_height, b.brow_height),
+            mouth_width: lerp(a.mouth_width, b.mouth_width),
+            mouth_height: lerp(a.mouth_height, b.mouth_height),
+            mouth_open: lerp(a.mouth_open, b.mouth_open),
+            mouth_offset: lerp2(a.mouth_offset, b.mouth_offset),
+            show_teeth: lerp(a.show_teeth, b.show_teeth),
+            show_tongue: lerp(a.show_tongue, b.show_tongue),
+            spiral_eyes: lerp(a.spiral_eyes, b.spiral_eyes),
+        }
+    }
+}
+
+/// Directional + rim lighting model used by every shaded surface, mirroring
+/// the secondary-light/rim-contour shading of expressive avatar shaders.
+#[derive(Debug, Clone, Copy)]
+pub struct Lighting {
+    /// Normalized direction *toward* the light source, in the same (dx, dy)
+    /// space as a shaded pixel's offset from its ellipse's center.
+    pub dir: (f32, f32),
+    pub rim_color: Rgba<u8>,
+    pub rim_strength: f32,
+}
+
+impl Default for Lighting {
+    fn default() -> Self {
+        // Matches the previous fixed top-left highlight / bottom-right shadow look.
+        Self {
+            dir: (-std::f32::consts::FRAC_1_SQRT_2, -std::f32::consts::FRAC_1_SQRT_2),
+            rim_color: Rgba([255, 255, 255, 255]),
+            rim_strength: 0.0,
+        }
+    }
+}
+
 /// Colors for the character with gradients
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterColors {
+    #[serde(with = "serde_rgba")]
     pub skin: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub skin_shadow: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub skin_highlight: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub outline: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub hair: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub hair_highlight: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub hair_shadow: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub eye_white: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub eye_pupil: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub eye_iris: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub eye_shine: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub mouth: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub mouth_dark: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub teeth: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub tongue: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub blush: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub shirt: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub shirt_shadow: Rgba<u8>,
+    #[serde(with = "serde_rgba")]
     pub shirt_highlight: Rgba<u8>,
 }
 
@@ -65,20 +318,264 @@ impl Default for CharacterColors {
     }
 }
 
+/// Full parametric geometry for a character, so every proportion baked into
+/// `Character`'s draw routines (head size, eye spacing, hair silhouette, ...)
+/// can vary per-character instead of being one hardcoded shape. Serializable
+/// so a generated character can be saved and reloaded as JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterParams {
+    pub face_width: f32,
+    pub face_height: f32,
+    pub eye_spacing: f32,
+    pub iris_scale: f32,
+    pub brow_thickness: f32,
+    pub hair_mass_width: f32,
+    pub hair_mass_height: f32,
+    pub hair_spikes: Vec<(f32, f32, f32)>,
+    pub ear_offset_x: f32,
+    pub ear_offset_y: f32,
+    pub mouth_width: f32,
+    pub mouth_height: f32,
+    /// How strongly neighboring hair blobs melt into each other where they'd
+    /// otherwise overlap, from 0 (drawn as separate lumps) to 1 (fully
+    /// merged into one silhouette). Feeds the `smin` blend radius in
+    /// `FieldBuffer::add_ellipse`.
+    pub hair_blend: f32,
+}
+
+impl Default for CharacterParams {
+    fn default() -> Self {
+        Self {
+            face_width: 115.0,
+            face_height: 135.0,
+            eye_spacing: 48.0,
+            iris_scale: 1.0,
+            brow_thickness: 5.5,
+            hair_mass_width: 105.0,
+            hair_mass_height: 75.0,
+            hair_spikes: vec![
+                (-65.0, -55.0, 25.0),
+                (-38.0, -72.0, 28.0),
+                (-8.0, -82.0, 32.0),
+                (25.0, -75.0, 30.0),
+                (52.0, -60.0, 26.0),
+                (72.0, -42.0, 22.0),
+                (-80.0, -32.0, 20.0),
+                (82.0, -28.0, 18.0),
+            ],
+            ear_offset_x: 108.0,
+            ear_offset_y: -10.0,
+            mouth_width: 32.0,
+            mouth_height: 16.0,
+            hair_blend: 0.5,
+        }
+    }
+}
+
+impl CharacterParams {
+    /// Sample every field within sensible ranges from a seeded RNG, so a
+    /// roster of distinct faces can be generated reproducibly.
+    pub fn random(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let base = Self::default();
+
+        let spike_count = rng.gen_range(6..=9);
+        let hair_spikes = (0..spike_count)
+            .map(|i| {
+                let angle = -std::f32::consts::PI * 0.85
+                    + (i as f32 / (spike_count - 1).max(1) as f32) * std::f32::consts::PI * 1.7;
+                let radius = rng.gen_range(55.0..85.0);
+                (angle.sin() * radius, angle.cos() * -radius * 0.8, rng.gen_range(16.0..34.0))
+            })
+            .collect();
+
+        Self {
+            face_width: base.face_width * rng.gen_range(0.9..1.1),
+            face_height: base.face_height * rng.gen_range(0.9..1.1),
+            eye_spacing: base.eye_spacing * rng.gen_range(0.85..1.15),
+            iris_scale: rng.gen_range(0.8..1.25),
+            brow_thickness: rng.gen_range(4.0..7.5),
+            hair_mass_width: base.hair_mass_width * rng.gen_range(0.85..1.2),
+            hair_mass_height: base.hair_mass_height * rng.gen_range(0.85..1.2),
+            hair_spikes,
+            ear_offset_x: base.ear_offset_x * rng.gen_range(0.92..1.08),
+            ear_offset_y: base.ear_offset_y * rng.gen_range(0.5..1.5),
+            mouth_width: base.mouth_width * rng.gen_range(0.85..1.2),
+            mouth_height: base.mouth_height * rng.gen_range(0.85..1.2),
+            hair_blend: rng.gen_range(0.2..0.8),
+        }
+    }
+
+    pub fn builder() -> CharacterParamsBuilder {
+        CharacterParamsBuilder::default()
+    }
+}
+
+/// Builder for manually tweaking individual `CharacterParams` fields while
+/// leaving the rest at their defaults.
+#[derive(Default)]
+pub struct CharacterParamsBuilder {
+    params: CharacterParamsOverrides,
+}
+
+#[derive(Default)]
+struct CharacterParamsOverrides {
+    face_size: Option<(f32, f32)>,
+    eye_spacing: Option<f32>,
+    iris_scale: Option<f32>,
+    brow_thickness: Option<f32>,
+    hair_mass_size: Option<(f32, f32)>,
+    hair_spikes: Option<Vec<(f32, f32, f32)>>,
+    ear_offset: Option<(f32, f32)>,
+    mouth_size: Option<(f32, f32)>,
+    hair_blend: Option<f32>,
+}
+
+impl CharacterParamsBuilder {
+    pub fn face_size(mut self, width: f32, height: f32) -> Self {
+        self.params.face_size = Some((width, height));
+        self
+    }
+
+    pub fn eye_spacing(mut self, spacing: f32) -> Self {
+        self.params.eye_spacing = Some(spacing);
+        self
+    }
+
+    pub fn iris_scale(mut self, scale: f32) -> Self {
+        self.params.iris_scale = Some(scale);
+        self
+    }
+
+    pub fn brow_thickness(mut self, thickness: f32) -> Self {
+        self.params.brow_thickness = Some(thickness);
+        self
+    }
+
+    pub fn hair_mass_size(mut self, width: f32, height: f32) -> Self {
+        self.params.hair_mass_size = Some((width, height));
+        self
+    }
+
+    pub fn hair_spikes(mut self, spikes: Vec<(f32, f32, f32)>) -> Self {
+        self.params.hair_spikes = Some(spikes);
+        self
+    }
+
+    pub fn ear_offset(mut self, x: f32, y: f32) -> Self {
+        self.params.ear_offset = Some((x, y));
+        self
+    }
+
+    pub fn mouth_size(mut self, width: f32, height: f32) -> Self {
+        self.params.mouth_size = Some((width, height));
+        self
+    }
+
+    pub fn hair_blend(mut self, blend: f32) -> Self {
+        self.params.hair_blend = Some(blend);
+        self
+    }
+
+    pub fn build(self) -> CharacterParams {
+        let mut params = CharacterParams::default();
+        let o = self.params;
+
+        if let Some((w, h)) = o.face_size {
+            params.face_width = w;
+            params.face_height = h;
+        }
+        if let Some(v) = o.eye_spacing {
+            params.eye_spacing = v;
+        }
+        if let Some(v) = o.iris_scale {
+            params.iris_scale = v;
+        }
+        if let Some(v) = o.brow_thickness {
+            params.brow_thickness = v;
+        }
+        if let Some((w, h)) = o.hair_mass_size {
+            params.hair_mass_width = w;
+            params.hair_mass_height = h;
+        }
+        if let Some(v) = o.hair_spikes {
+            params.hair_spikes = v;
+        }
+        if let Some((x, y)) = o.ear_offset {
+            params.ear_offset_x = x;
+            params.ear_offset_y = y;
+        }
+        if let Some((w, h)) = o.mouth_size {
+            params.mouth_width = w;
+            params.mouth_height = h;
+        }
+        if let Some(v) = o.hair_blend {
+            params.hair_blend = v;
+        }
+
+        params
+    }
+}
+
 /// Character renderer with anti-aliased quality
+#[derive(Clone)]
 pub struct Character {
     colors: CharacterColors,
+    params: CharacterParams,
+    /// Normalized look target in -1..1 on each axis, tracked by both eyes.
+    gaze: (f32, f32),
+    lighting: Lighting,
 }
 
 impl Character {
     pub fn new() -> Self {
+        Self::with_params(CharacterColors::default(), CharacterParams::default())
+    }
+
+    /// Build a character from an explicit colors/geometry descriptor, e.g.
+    /// one produced by `CharacterParams::random` or loaded back from JSON.
+    pub fn with_params(colors: CharacterColors, params: CharacterParams) -> Self {
         Self {
-            colors: CharacterColors::default(),
+            colors,
+            params,
+            gaze: (0.0, 0.0),
+            lighting: Lighting::default(),
         }
     }
 
+    /// Set the gaze/look target (each axis clamped to -1..1).
+    pub fn set_gaze(&mut self, gaze: (f32, f32)) {
+        self.gaze = (gaze.0.clamp(-1.0, 1.0), gaze.1.clamp(-1.0, 1.0));
+    }
+
+    /// Art-direct the light direction and rim-light contour used by every
+    /// shaded surface.
+    pub fn set_lighting(&mut self, lighting: Lighting) {
+        self.lighting = lighting;
+    }
+
     /// Render the character with the specified expression
     pub fn render(&self, expression: Expression, scale: f32) -> RgbaImage {
+        self.render_with_gaze(expression, scale, self.gaze, 1.0)
+    }
+
+    /// Render the character with an explicit gaze target and eyelid openness
+    /// (0.0 = fully closed/blinking, 1.0 = fully open), independent of the
+    /// character's own stored `gaze`.
+    pub fn render_with_gaze(&self, expression: Expression, scale: f32, gaze: (f32, f32), eye_openness: f32) -> RgbaImage {
+        self.render_face(FaceParams::for_expression(expression), scale, gaze, eye_openness)
+    }
+
+    /// Render a smooth tween between two expressions at position `t`
+    /// (0.0 = `a`, 1.0 = `b`), blending every `FaceParams` field. Lets a
+    /// sequence of frames morph Neutral into Shocked into MindBlown and back
+    /// for GIF/sprite-sheet export instead of hard-cutting between poses.
+    pub fn render_blend(&self, a: Expression, b: Expression, t: f32, scale: f32) -> RgbaImage {
+        let face = FaceParams::lerp(&FaceParams::for_expression(a), &FaceParams::for_expression(b), t.clamp(0.0, 1.0));
+        self.render_face(face, scale, self.gaze, 1.0)
+    }
+
+    fn render_face(&self, face: FaceParams, scale: f32, gaze: (f32, f32), eye_openness: f32) -> RgbaImage {
         let base_width = 500;
         let base_height = 600;
         let width = (base_width as f32 * scale) as u32;
@@ -95,7 +592,7 @@ impl Character {
         self.draw_head(&mut img, cx, cy, s);
         self.draw_ears(&mut img, cx, cy, s);
         self.draw_hair(&mut img, cx, cy - 60.0 * s, s);
-        self.draw_face(&mut img, cx, cy, s, expression);
+        self.draw_face(&mut img, cx, cy, s, face, gaze, eye_openness.clamp(0.0, 1.0));
 
         img
     }
@@ -126,7 +623,8 @@ impl Character {
         }
     }
 
-    // Anti-aliased ellipse with gradient shading
+    // Anti-aliased ellipse, Lambert-shaded against `self.lighting` with a rim
+    // light along the silhouette.
     fn draw_shaded_ellipse(&self, img: &mut RgbaImage, cx: f32, cy: f32, rx: f32, ry: f32,
                            base: Rgba<u8>, shadow: Rgba<u8>, highlight: Rgba<u8>) {
         let x_start = (cx - rx - 2.0).max(0.0) as u32;
@@ -134,6 +632,8 @@ impl Character {
         let y_start = (cy - ry - 2.0).max(0.0) as u32;
         let y_end = ((cy + ry + 2.0) as u32).min(img.height());
 
+        let light = self.lighting;
+
         for y in y_start..y_end {
             for x in x_start..x_end {
                 let dx = (x as f32 - cx) / rx;
@@ -141,17 +641,26 @@ impl Character {
                 let dist = (dx * dx + dy * dy).sqrt();
 
                 if dist < 1.0 {
-                    // Gradient from top-left (highlight) to bottom-right (shadow)
-                    let gradient = ((dx + dy) / 2.0 * 0.5 + 0.5).clamp(0.0, 1.0);
-
-                    // Top area gets highlight
-                    let vert_gradient = ((dy + 1.0) / 2.0).clamp(0.0, 1.0);
-
-                    let r = Self::lerp3(highlight[0], base[0], shadow[0], gradient, vert_gradient);
-                    let g = Self::lerp3(highlight[1], base[1], shadow[1], gradient, vert_gradient);
-                    let b = Self::lerp3(highlight[2], base[2], shadow[2], gradient, vert_gradient);
+                    // The ellipse-space offset doubles as a surface normal;
+                    // Lambert term against the light direction picks the
+                    // color along a shadow -> base -> highlight gradient.
+                    let normal_len = dist.max(1e-4);
+                    let (nx, ny) = (dx / normal_len, dy / normal_len);
+                    let dot = nx * light.dir.0 + ny * light.dir.1;
+                    let lambert = dot.clamp(0.0, 1.0);
+
+                    let r = Self::lambert_lerp3(shadow[0], base[0], highlight[0], lambert);
+                    let g = Self::lambert_lerp3(shadow[1], base[1], highlight[1], lambert);
+                    let b = Self::lambert_lerp3(shadow[2], base[2], highlight[2], lambert);
+
+                    let mut color = Rgba([r as u8, g as u8, b as u8, 255]);
+
+                    // Rim light: near the silhouette, facing away from the light.
+                    if dist > 0.85 && dot < 0.0 && light.rim_strength > 0.0 {
+                        let edge_falloff = ((dist - 0.85) / 0.15).clamp(0.0, 1.0);
+                        color = Self::add_rim(color, light.rim_color, light.rim_strength * edge_falloff);
+                    }
 
-                    let color = Rgba([r as u8, g as u8, b as u8, 255]);
                     self.blend_pixel(img, x, y, color);
                 } else if dist < 1.05 {
                     // Anti-aliased edge
@@ -163,15 +672,67 @@ impl Character {
         }
     }
 
-    fn lerp3(highlight: u8, base: u8, shadow: u8, horiz: f32, vert: f32) -> f32 {
-        let mid = Self::lerp(base as f32, shadow as f32, horiz);
-        Self::lerp(highlight as f32, mid, vert)
+    /// Three-stop gradient across a single Lambert scalar: 0 = shadow,
+    /// 0.5 = base, 1 = highlight.
+    fn lambert_lerp3(shadow: u8, base: u8, highlight: u8, t: f32) -> f32 {
+        if t < 0.5 {
+            Self::lerp(shadow as f32, base as f32, t * 2.0)
+        } else {
+            Self::lerp(base as f32, highlight as f32, (t - 0.5) * 2.0)
+        }
+    }
+
+    fn add_rim(color: Rgba<u8>, rim: Rgba<u8>, amount: f32) -> Rgba<u8> {
+        let amount = amount.clamp(0.0, 1.0);
+        let add = |c: u8, r: u8| (c as f32 + r as f32 * amount).min(255.0) as u8;
+        Rgba([add(color[0], rim[0]), add(color[1], rim[1]), add(color[2], rim[2]), color[3]])
     }
 
     fn lerp(a: f32, b: f32, t: f32) -> f32 {
         a + (b - a) * t
     }
 
+    /// Rasterize a signed-distance field built by `FieldBuffer`: fill where
+    /// the field is negative, anti-alias a thin band around the zero
+    /// crossing, and derive a shading normal from the field's own gradient
+    /// (via finite differences) so the merged silhouette still shades like
+    /// one continuous surface under `self.lighting`.
+    fn rasterize_field(&self, img: &mut RgbaImage, field: &FieldBuffer,
+                       base: Rgba<u8>, shadow: Rgba<u8>, highlight: Rgba<u8>) {
+        const EDGE: f32 = 0.05;
+        let light = self.lighting;
+
+        for ly in 0..field.height {
+            for lx in 0..field.width {
+                let x = field.origin_x + lx as i32;
+                let y = field.origin_y + ly as i32;
+                if x < 0 || y < 0 {
+                    continue;
+                }
+
+                let d = field.get(x, y);
+                if d >= EDGE {
+                    continue;
+                }
+
+                let gx = field.get(x + 1, y) - field.get(x - 1, y);
+                let gy = field.get(x, y + 1) - field.get(x, y - 1);
+                let glen = (gx * gx + gy * gy).sqrt().max(1e-4);
+                let (nx, ny) = (gx / glen, gy / glen);
+                let dot = nx * light.dir.0 + ny * light.dir.1;
+                let lambert = dot.clamp(0.0, 1.0);
+
+                let r = Self::lambert_lerp3(shadow[0], base[0], highlight[0], lambert);
+                let g = Self::lambert_lerp3(shadow[1], base[1], highlight[1], lambert);
+                let b = Self::lambert_lerp3(shadow[2], base[2], highlight[2], lambert);
+
+                let coverage = ((EDGE - d) / (EDGE * 2.0)).clamp(0.0, 1.0);
+                let color = Rgba([r as u8, g as u8, b as u8, (coverage * 255.0) as u8]);
+                self.blend_pixel(img, x as u32, y as u32, color);
+            }
+        }
+    }
+
     // Anti-aliased circle
     fn draw_smooth_circle(&self, img: &mut RgbaImage, cx: f32, cy: f32, r: f32, color: Rgba<u8>) {
         self.draw_smooth_ellipse(img, cx, cy, r, r, color);
@@ -246,6 +807,134 @@ impl Character {
         img.put_pixel(x, y, Rgba([r as u8, g as u8, b as u8, (out_a * 255.0) as u8]));
     }
 
+    /// Fill the region enclosed by a closed cubic-Bézier path with a solid color.
+    /// `start` is the path's first anchor; `segments` walk the rest of the loop
+    /// back around to it.
+    fn draw_filled_path(&self, img: &mut RgbaImage, start: (f32, f32), segments: &[BezierSegment], color: Rgba<u8>) {
+        self.fill_path(img, start, segments, |_, _| color);
+    }
+
+    /// Scanline-fill (even-odd rule) the polygon obtained by flattening the
+    /// path, supersampling each pixel over `SUBSAMPLES` sub-scanlines and
+    /// blending the coverage-weighted color via `blend_pixel`.
+    fn fill_path(&self, img: &mut RgbaImage, start: (f32, f32), segments: &[BezierSegment],
+                color_at: impl Fn(f32, f32) -> Rgba<u8>) {
+        const SUBSAMPLES: u32 = 4;
+
+        let polyline = Self::flatten_path(start, segments);
+        if polyline.len() < 3 {
+            return;
+        }
+
+        let (min_x, min_y, max_x, max_y) = Self::bounds(&polyline);
+        let x_start = min_x.floor().max(0.0) as u32;
+        let x_end = (max_x.ceil() as u32).min(img.width());
+        let y_start = min_y.floor().max(0.0) as u32;
+        let y_end = (max_y.ceil() as u32).min(img.height());
+
+        for y in y_start..y_end {
+            for x in x_start..x_end {
+                let mut coverage = 0u32;
+                for sub in 0..SUBSAMPLES {
+                    let sample_y = y as f32 + (sub as f32 + 0.5) / SUBSAMPLES as f32;
+                    let crossings = Self::scanline_crossings(&polyline, sample_y);
+                    if Self::x_is_inside(&crossings, x as f32 + 0.5) {
+                        coverage += 1;
+                    }
+                }
+                if coverage > 0 {
+                    let color = color_at(x as f32 + 0.5, y as f32 + 0.5);
+                    let alpha = (color[3] as f32 * coverage as f32 / SUBSAMPLES as f32) as u8;
+                    self.blend_pixel(img, x, y, Rgba([color[0], color[1], color[2], alpha]));
+                }
+            }
+        }
+    }
+
+    fn bounds(polyline: &[(f32, f32)]) -> (f32, f32, f32, f32) {
+        polyline.iter().fold(
+            (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+            |(min_x, min_y, max_x, max_y), &(x, y)| {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            },
+        )
+    }
+
+    /// x-coordinates where the polygon edges cross horizontal line `y`.
+    fn scanline_crossings(polyline: &[(f32, f32)], y: f32) -> Vec<f32> {
+        let n = polyline.len();
+        let mut xs: Vec<f32> = (0..n)
+            .filter_map(|i| {
+                let (x1, y1) = polyline[i];
+                let (x2, y2) = polyline[(i + 1) % n];
+                if (y1 <= y && y2 > y) || (y2 <= y && y1 > y) {
+                    Some(x1 + (y - y1) / (y2 - y1) * (x2 - x1))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        xs
+    }
+
+    fn x_is_inside(crossings: &[f32], x: f32) -> bool {
+        crossings.chunks(2).any(|pair| pair.len() == 2 && x >= pair[0] && x < pair[1])
+    }
+
+    /// Flatten a closed path of cubic Bézier segments into a polyline.
+    fn flatten_path(start: (f32, f32), segments: &[BezierSegment]) -> Vec<(f32, f32)> {
+        let mut points = vec![start];
+        let mut p0 = start;
+        for seg in segments {
+            Self::flatten_cubic(p0, seg.control1, seg.control2, seg.end, 0, &mut points);
+            p0 = seg.end;
+        }
+        points
+    }
+
+    /// Adaptively subdivide a cubic Bézier `B(t) = (1-t)³P0 + 3(1-t)²t·P1 +
+    /// 3(1-t)t²·P2 + t³·P3` until its control polygon is within ~0.3px of the
+    /// chord, then push the flattened points into `out`.
+    fn flatten_cubic(p0: (f32, f32), p1: (f32, f32), p2: (f32, f32), p3: (f32, f32),
+                     depth: u32, out: &mut Vec<(f32, f32)>) {
+        const FLATNESS: f32 = 0.3;
+        const MAX_DEPTH: u32 = 16;
+
+        if depth >= MAX_DEPTH || Self::cubic_is_flat(p0, p1, p2, p3, FLATNESS) {
+            out.push(p3);
+            return;
+        }
+
+        // De Casteljau subdivision at t = 0.5.
+        let p01 = Self::midpoint(p0, p1);
+        let p12 = Self::midpoint(p1, p2);
+        let p23 = Self::midpoint(p2, p3);
+        let p012 = Self::midpoint(p01, p12);
+        let p123 = Self::midpoint(p12, p23);
+        let p0123 = Self::midpoint(p012, p123);
+
+        Self::flatten_cubic(p0, p01, p012, p0123, depth + 1, out);
+        Self::flatten_cubic(p0123, p123, p23, p3, depth + 1, out);
+    }
+
+    fn cubic_is_flat(p0: (f32, f32), p1: (f32, f32), p2: (f32, f32), p3: (f32, f32), tolerance: f32) -> bool {
+        Self::point_line_distance(p1, p0, p3) <= tolerance && Self::point_line_distance(p2, p0, p3) <= tolerance
+    }
+
+    fn point_line_distance(p: (f32, f32), a: (f32, f32), b: (f32, f32)) -> f32 {
+        let (dx, dy) = (b.0 - a.0, b.1 - a.1);
+        let len = (dx * dx + dy * dy).sqrt();
+        if len < 1e-6 {
+            return ((p.0 - a.0).powi(2) + (p.1 - a.1).powi(2)).sqrt();
+        }
+        ((p.0 - a.0) * dy - (p.1 - a.1) * dx).abs() / len
+    }
+
+    fn midpoint(a: (f32, f32), b: (f32, f32)) -> (f32, f32) {
+        ((a.0 + b.0) * 0.5, (a.1 + b.1) * 0.5)
+    }
+
     fn draw_body(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
         // Shirt with gradient shading
         self.draw_shaded_ellipse(img, cx, cy, 140.0 * s, 120.0 * s,
@@ -265,50 +954,64 @@ impl Character {
     }
 
     fn draw_head(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
+        let fw = self.params.face_width;
+        let fh = self.params.face_height;
+
         // Head outline first (slightly larger)
-        self.draw_smooth_ellipse(img, cx, cy, 118.0 * s, 138.0 * s, self.colors.outline);
+        self.draw_smooth_ellipse(img, cx, cy, (fw + 3.0) * s, (fh + 3.0) * s, self.colors.outline);
 
         // Main head with gradient
-        self.draw_shaded_ellipse(img, cx, cy, 115.0 * s, 135.0 * s,
+        self.draw_shaded_ellipse(img, cx, cy, fw * s, fh * s,
             self.colors.skin, self.colors.skin_shadow, self.colors.skin_highlight);
     }
 
     fn draw_ears(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
+        let ox = self.params.ear_offset_x;
+        let oy = self.params.ear_offset_y;
+
         // Left ear
-        self.draw_shaded_ellipse(img, cx - 108.0 * s, cy - 10.0 * s, 22.0 * s, 32.0 * s,
+        self.draw_shaded_ellipse(img, cx - ox * s, cy + oy * s, 22.0 * s, 32.0 * s,
             self.colors.skin, self.colors.skin_shadow, self.colors.skin);
-        self.draw_smooth_ellipse(img, cx - 108.0 * s, cy - 10.0 * s, 12.0 * s, 18.0 * s,
+        self.draw_smooth_ellipse(img, cx - ox * s, cy + oy * s, 12.0 * s, 18.0 * s,
             self.colors.skin_shadow);
 
         // Right ear
-        self.draw_shaded_ellipse(img, cx + 108.0 * s, cy - 10.0 * s, 22.0 * s, 32.0 * s,
+        self.draw_shaded_ellipse(img, cx + ox * s, cy + oy * s, 22.0 * s, 32.0 * s,
             self.colors.skin, self.colors.skin_shadow, self.colors.skin);
-        self.draw_smooth_ellipse(img, cx + 108.0 * s, cy - 10.0 * s, 12.0 * s, 18.0 * s,
+        self.draw_smooth_ellipse(img, cx + ox * s, cy + oy * s, 12.0 * s, 18.0 * s,
             self.colors.skin_shadow);
     }
 
     fn draw_hair(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
-        // Main hair mass with gradient
-        self.draw_shaded_ellipse(img, cx, cy - 25.0 * s, 105.0 * s, 75.0 * s,
-            self.colors.hair, self.colors.hair_shadow, self.colors.hair_highlight);
-
-        // Stylized hair spikes with shading
-        let spikes = [
-            (-65.0, -55.0, 25.0),
-            (-38.0, -72.0, 28.0),
-            (-8.0, -82.0, 32.0),
-            (25.0, -75.0, 30.0),
-            (52.0, -60.0, 26.0),
-            (72.0, -42.0, 22.0),
-            (-80.0, -32.0, 20.0),
-            (82.0, -28.0, 18.0),
+        let mass_cx = cx;
+        let mass_cy = cy - 25.0 * s;
+
+        // Every blob making up the hair (the central mass plus one per
+        // spike) as (cx, cy, rx, ry). Rather than drawing these as separate
+        // shapes, they're accumulated into a shared signed-distance field
+        // below so overlapping/nearby blobs melt into one continuous
+        // silhouette instead of showing their individual outlines.
+        let mut blobs: Vec<(f32, f32, f32, f32)> = vec![
+            (mass_cx, mass_cy, self.params.hair_mass_width * s, self.params.hair_mass_height * s),
         ];
-
-        for (ox, oy, size) in spikes.iter() {
-            self.draw_shaded_ellipse(img, cx + ox * s, cy + oy * s,
-                size * s, size * 1.3 * s,
-                self.colors.hair, self.colors.hair_shadow, self.colors.hair_highlight);
+        blobs.extend(self.params.hair_spikes.iter().map(|&(ox, oy, size)| {
+            (mass_cx + ox * s, mass_cy + oy * s, size * s * 0.9, size * s * 1.6)
+        }));
+
+        let blend = self.params.hair_blend.clamp(0.0, 1.0) * 0.6;
+        let pad = 40.0 * s + blend;
+        let (min_x, min_y, max_x, max_y) = blobs.iter().fold(
+            (f32::MAX, f32::MAX, f32::MIN, f32::MIN),
+            |(min_x, min_y, max_x, max_y), &(bx, by, brx, bry)| {
+                (min_x.min(bx - brx), min_y.min(by - bry), max_x.max(bx + brx), max_y.max(by + bry))
+            },
+        );
+
+        let mut field = FieldBuffer::new(min_x - pad, min_y - pad, max_x + pad, max_y + pad, img.width(), img.height());
+        for &(bx, by, brx, bry) in &blobs {
+            field.add_ellipse(bx, by, brx, bry, blend);
         }
+        self.rasterize_field(img, &field, self.colors.hair, self.colors.hair_shadow, self.colors.hair_highlight);
 
         // Hair highlights (shiny spots)
         self.draw_smooth_circle(img, cx - 32.0 * s, cy - 62.0 * s, 14.0 * s, self.colors.hair_highlight);
@@ -322,31 +1025,104 @@ impl Character {
             self.colors.hair, self.colors.hair_shadow, self.colors.hair_highlight);
     }
 
-    fn draw_face(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32, expression: Expression) {
+    fn draw_face(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32, face: FaceParams,
+                 gaze: (f32, f32), eye_openness: f32) {
         // Subtle cheek blush
         self.draw_smooth_ellipse(img, cx - 58.0 * s, cy + 32.0 * s, 28.0 * s, 16.0 * s, self.colors.blush);
         self.draw_smooth_ellipse(img, cx + 58.0 * s, cy + 32.0 * s, 28.0 * s, 16.0 * s, self.colors.blush);
 
-        match expression {
-            Expression::Neutral => self.draw_neutral_face(img, cx, cy, s),
-            Expression::Shocked => self.draw_shocked_face(img, cx, cy, s),
-            Expression::Serious => self.draw_serious_face(img, cx, cy, s),
-            Expression::Mischievous => self.draw_mischievous_face(img, cx, cy, s),
-            Expression::MindBlown => self.draw_mind_blown_face(img, cx, cy, s),
-            Expression::Whispering => self.draw_whispering_face(img, cx, cy, s),
+        let eye_y = cy - 18.0 * s;
+        let eye_offset = self.params.eye_spacing * s;
+
+        // Eyes
+        self.draw_eye(img, cx - eye_offset, eye_y, s, face.eye_width, face.eye_height,
+            face.pupil_offset.0, face.pupil_offset.1, gaze, eye_openness);
+        self.draw_eye(img, cx + eye_offset, eye_y, s, face.eye_width, face.eye_height,
+            face.pupil_offset.0, face.pupil_offset.1, gaze, eye_openness);
+
+        // MindBlown's concentric spiral, crossfaded in over the ordinary
+        // iris/pupil as `spiral_eyes` blends toward 1.
+        if face.spiral_eyes > 0.001 {
+            for side in [-1.0, 1.0] {
+                self.draw_spiral_overlay(img, cx + side * eye_offset, eye_y, s, face.spiral_eyes);
+            }
         }
+
+        // Eyebrows
+        self.draw_eyebrow(img, cx - eye_offset, eye_y - face.brow_height.0 * s, s, face.brow_angle.0, self.params.brow_thickness);
+        self.draw_eyebrow(img, cx + eye_offset, eye_y - face.brow_height.1 * s, s, face.brow_angle.1, self.params.brow_thickness);
+
+        // Nose
+        self.draw_nose(img, cx, cy + 18.0 * s, s);
+
+        self.draw_mouth(img, cx + face.mouth_offset.0 * s, cy + face.mouth_offset.1 * s, s, face);
+    }
+
+    /// Mouth whose interior darkens toward `mouth_dark` as `mouth_open`
+    /// grows, and whose teeth/tongue crossfade in by `show_teeth`/`show_tongue`.
+    fn draw_mouth(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32, face: FaceParams) {
+        let mw = face.mouth_width * s;
+        let mh = (face.mouth_height * s).max(1.0);
+
+        if face.mouth_open < 0.05 {
+            self.draw_lip_path(img, cx, cy, mw, mh, 0.0, self.colors.outline);
+            self.draw_lip_path(img, cx, cy, mw - 2.0 * s, mh - 2.0 * s, 0.0, self.colors.mouth);
+        } else {
+            self.draw_ellipse_outline(img, cx, cy, mw, mh, 3.0 * s, self.colors.outline);
+            let interior = Self::lerp_color(self.colors.mouth, self.colors.mouth_dark, face.mouth_open);
+            self.draw_smooth_ellipse(img, cx, cy, mw, mh, interior);
+        }
+
+        if face.show_teeth > 0.001 {
+            self.draw_smooth_ellipse(img, cx, cy - mh * 0.35, mw * 0.7, mh * 0.3,
+                Self::with_alpha(self.colors.teeth, face.show_teeth));
+        }
+
+        if face.show_tongue > 0.001 {
+            self.draw_smooth_ellipse(img, cx, cy + mh * 0.4, mw * 0.55, mh * 0.35,
+                Self::with_alpha(self.colors.tongue, face.show_tongue));
+        }
+    }
+
+    fn draw_spiral_overlay(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32, amount: f32) {
+        self.draw_smooth_circle(img, cx, cy, 22.0 * s, Self::with_alpha(self.colors.eye_pupil, amount));
+        self.draw_smooth_circle(img, cx, cy, 16.0 * s, Self::with_alpha(self.colors.eye_white, amount));
+        self.draw_smooth_circle(img, cx, cy, 10.0 * s, Self::with_alpha(self.colors.eye_pupil, amount));
+        self.draw_smooth_circle(img, cx, cy, 5.0 * s, Self::with_alpha(self.colors.eye_white, amount));
+    }
+
+    fn with_alpha(color: Rgba<u8>, alpha: f32) -> Rgba<u8> {
+        Rgba([color[0], color[1], color[2], (color[3] as f32 * alpha.clamp(0.0, 1.0)) as u8])
+    }
+
+    fn lerp_color(a: Rgba<u8>, b: Rgba<u8>, t: f32) -> Rgba<u8> {
+        Rgba([
+            Self::lerp(a[0] as f32, b[0] as f32, t) as u8,
+            Self::lerp(a[1] as f32, b[1] as f32, t) as u8,
+            Self::lerp(a[2] as f32, b[2] as f32, t) as u8,
+            Self::lerp(a[3] as f32, b[3] as f32, t) as u8,
+        ])
     }
 
     fn draw_eye(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32,
-                width: f32, height: f32, pupil_ox: f32, pupil_oy: f32) {
+                width: f32, height: f32, pupil_ox: f32, pupil_oy: f32,
+                gaze: (f32, f32), eye_openness: f32) {
         // Eye white
         self.draw_smooth_ellipse(img, cx, cy, width * s, height * s, self.colors.eye_white);
 
         // Eye outline
         self.draw_ellipse_outline(img, cx, cy, width * s, height * s, 2.5 * s, self.colors.outline);
 
+        // Iris/pupil travel is clamped to `width - iris_r - margin` on both
+        // axes, which keeps the iris inside the white horizontally; the
+        // vertical travel uses the same bound and is not limited by `height`.
+        let iris_r = height * 0.55 * self.params.iris_scale;
+        let margin = 2.0;
+        let max_travel = (width - iris_r - margin).max(0.0);
+        let pupil_ox = (pupil_ox + gaze.0 * max_travel).clamp(-max_travel, max_travel);
+        let pupil_oy = (pupil_oy + gaze.1 * max_travel).clamp(-max_travel, max_travel);
+
         // Iris
-        let iris_r = height * 0.55;
         self.draw_smooth_circle(img, cx + pupil_ox * s, cy + pupil_oy * s, iris_r * s, self.colors.eye_iris);
 
         // Pupil
@@ -358,6 +1134,42 @@ impl Character {
             6.0 * s, self.colors.eye_shine);
         self.draw_smooth_circle(img, cx + (pupil_ox + 4.0) * s, cy + (pupil_oy + 4.0) * s,
             3.0 * s, self.colors.eye_shine);
+
+        self.draw_eyelids(img, cx, cy, s, width, height, eye_openness);
+    }
+
+    /// Occlude the eye from the top and bottom so `eye_openness` of 0 draws a
+    /// fully closed (blinking) eye and 1 leaves it untouched.
+    fn draw_eyelids(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32, width: f32, height: f32, eye_openness: f32) {
+        if eye_openness >= 1.0 {
+            return;
+        }
+
+        let lid_rx = width * s + 2.0;
+        let lid_ry = height * s + 2.0;
+        // Each lid's edge retreats from the eye's center toward its outer
+        // bound as `eye_openness` grows, so the two meet at `cy` when closed.
+        let upper_edge = cy - lid_ry * eye_openness;
+        let lower_edge = cy + lid_ry * eye_openness;
+
+        // Upper lid covers everything above its edge.
+        let upper_h = (upper_edge - (cy - lid_ry)).max(0.0);
+        if upper_h > 0.0 {
+            draw_filled_rect_mut(img, Rect::at((cx - lid_rx) as i32, (cy - lid_ry) as i32)
+                .of_size(lid_rx as u32 * 2, upper_h.ceil() as u32), self.colors.skin);
+        }
+
+        // Lower lid covers everything below its edge.
+        let lower_h = ((cy + lid_ry) - lower_edge).max(0.0);
+        if lower_h > 0.0 {
+            draw_filled_rect_mut(img, Rect::at((cx - lid_rx) as i32, lower_edge as i32)
+                .of_size(lid_rx as u32 * 2, lower_h.ceil() as u32), self.colors.skin_shadow);
+        }
+
+        // Thin lash line along the upper lid's edge.
+        let lash_y = upper_edge.max(0.0) as i32;
+        draw_filled_rect_mut(img, Rect::at((cx - lid_rx) as i32, lash_y)
+            .of_size(lid_rx as u32 * 2, (1.5 * s).max(1.0) as u32), self.colors.outline);
     }
 
     fn draw_eyebrow(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32, angle: f32, thickness: f32) {
@@ -395,154 +1207,100 @@ impl Character {
         self.draw_smooth_ellipse(img, cx, cy, 9.0 * s, 6.0 * s, self.colors.skin_shadow);
     }
 
-    fn draw_smile(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32,
-                  width: f32, height: f32, show_teeth: bool) {
-        // Mouth outline
-        self.draw_ellipse_outline(img, cx, cy, width * s, height * s, 2.0 * s, self.colors.outline);
-
-        // Mouth interior
-        self.draw_smooth_ellipse(img, cx, cy, width * s, height * s, self.colors.mouth);
-
-        if show_teeth {
-            self.draw_smooth_ellipse(img, cx, cy - height * 0.25 * s,
-                width * 0.75 * s, height * 0.4 * s, self.colors.teeth);
-        }
+    /// Closed lip outline: a flattened upper arc from left to right corner and
+    /// a rounder lower arc back, as two cubic-Bézier segments.
+    fn draw_lip_path(&self, img: &mut RgbaImage, cx: f32, cy: f32, w: f32, h: f32, y_offset: f32, color: Rgba<u8>) {
+        let left = (cx - w, cy + y_offset);
+        let right = (cx + w, cy + y_offset);
+
+        let upper = BezierSegment {
+            control1: (cx - w * 0.5, cy + y_offset - h * 0.6),
+            control2: (cx + w * 0.5, cy + y_offset - h * 0.6),
+            end: right,
+        };
+        let lower = BezierSegment {
+            control1: (cx + w * 0.5, cy + y_offset + h * 1.2),
+            control2: (cx - w * 0.5, cy + y_offset + h * 1.2),
+            end: left,
+        };
+
+        self.draw_filled_path(img, left, &[upper, lower], color);
     }
 
-    fn draw_neutral_face(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
-        let eye_y = cy - 18.0 * s;
-        let eye_offset = 48.0 * s;
-
-        // Eyes
-        self.draw_eye(img, cx - eye_offset, eye_y, s, 26.0, 32.0, 0.0, 0.0);
-        self.draw_eye(img, cx + eye_offset, eye_y, s, 26.0, 32.0, 0.0, 0.0);
-
-        // Eyebrows
-        self.draw_eyebrow(img, cx - eye_offset, eye_y - 42.0 * s, s, 0.0, 5.5);
-        self.draw_eyebrow(img, cx + eye_offset, eye_y - 42.0 * s, s, 0.0, 5.5);
-
-        // Nose
-        self.draw_nose(img, cx, cy + 18.0 * s, s);
+}
 
-        // Smile
-        self.draw_smile(img, cx, cy + 58.0 * s, s, 32.0, 16.0, true);
+impl Default for Character {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    fn draw_shocked_face(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
-        let eye_y = cy - 18.0 * s;
-        let eye_offset = 48.0 * s;
-
-        // Wide eyes
-        self.draw_eye(img, cx - eye_offset, eye_y, s, 34.0, 42.0, 0.0, -4.0);
-        self.draw_eye(img, cx + eye_offset, eye_y, s, 34.0, 42.0, 0.0, -4.0);
-
-        // Raised eyebrows
-        self.draw_eyebrow(img, cx - eye_offset, eye_y - 58.0 * s, s, -0.35, 5.0);
-        self.draw_eyebrow(img, cx + eye_offset, eye_y - 58.0 * s, s, 0.35, 5.0);
-
-        // Nose
-        self.draw_nose(img, cx, cy + 18.0 * s, s);
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        // Open mouth "O"
-        self.draw_ellipse_outline(img, cx, cy + 62.0 * s, 32.0 * s, 38.0 * s, 3.0 * s, self.colors.outline);
-        self.draw_smooth_ellipse(img, cx, cy + 62.0 * s, 32.0 * s, 38.0 * s, self.colors.mouth_dark);
-        self.draw_smooth_ellipse(img, cx, cy + 50.0 * s, 24.0 * s, 10.0 * s, self.colors.teeth);
+    #[test]
+    fn smin_matches_hard_min_when_k_is_zero() {
+        assert_eq!(FieldBuffer::smin(0.3, -0.1, 0.0), -0.1);
+        assert_eq!(FieldBuffer::smin(-0.4, 0.2, 0.0), -0.4);
     }
 
-    fn draw_serious_face(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
-        let eye_y = cy - 18.0 * s;
-        let eye_offset = 48.0 * s;
-
-        // Narrowed eyes
-        self.draw_eye(img, cx - eye_offset, eye_y, s, 30.0, 20.0, 0.0, 0.0);
-        self.draw_eye(img, cx + eye_offset, eye_y, s, 30.0, 20.0, 0.0, 0.0);
-
-        // Furrowed eyebrows
-        self.draw_eyebrow(img, cx - eye_offset, eye_y - 32.0 * s, s, 0.45, 6.5);
-        self.draw_eyebrow(img, cx + eye_offset, eye_y - 32.0 * s, s, -0.45, 6.5);
-
-        // Nose
-        self.draw_nose(img, cx, cy + 18.0 * s, s);
-
-        // Flat mouth
-        self.draw_smooth_ellipse(img, cx, cy + 58.0 * s, 28.0 * s, 5.0 * s, self.colors.outline);
+    #[test]
+    fn smin_dips_below_the_hard_min_for_close_values() {
+        let blended = FieldBuffer::smin(0.1, 0.1, 0.4);
+        assert!(blended < 0.1, "expected a smooth blend below the hard min, got {blended}");
     }
 
-    fn draw_mischievous_face(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
-        let eye_y = cy - 18.0 * s;
-        let eye_offset = 48.0 * s;
-
-        // Sly eyes looking to side
-        self.draw_eye(img, cx - eye_offset, eye_y, s, 26.0, 24.0, 5.0, 0.0);
-        self.draw_eye(img, cx + eye_offset, eye_y, s, 26.0, 24.0, 5.0, 0.0);
-
-        // Asymmetric eyebrows
-        self.draw_eyebrow(img, cx - eye_offset, eye_y - 38.0 * s, s, 0.25, 5.0);
-        self.draw_eyebrow(img, cx + eye_offset, eye_y - 45.0 * s, s, -0.3, 5.0);
-
-        // Nose
-        self.draw_nose(img, cx, cy + 18.0 * s, s);
-
-        // Smirk
-        self.draw_smooth_ellipse(img, cx + 10.0 * s, cy + 58.0 * s, 34.0 * s, 14.0 * s, self.colors.mouth);
-        self.draw_ellipse_outline(img, cx + 10.0 * s, cy + 58.0 * s, 34.0 * s, 14.0 * s, 2.0 * s, self.colors.outline);
-        self.draw_smooth_ellipse(img, cx + 22.0 * s, cy + 54.0 * s, 14.0 * s, 7.0 * s, self.colors.teeth);
+    #[test]
+    fn smin_converges_to_hard_min_outside_the_blend_radius() {
+        assert_eq!(FieldBuffer::smin(-1.0, 5.0, 0.2), -1.0);
     }
 
-    fn draw_mind_blown_face(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
-        let eye_y = cy - 18.0 * s;
-        let eye_offset = 48.0 * s;
-
-        // Huge spiral eyes
-        for side in [-1.0, 1.0] {
-            let ex = cx + side * eye_offset;
-            self.draw_smooth_ellipse(img, ex, eye_y, 38.0 * s, 48.0 * s, self.colors.eye_white);
-            self.draw_ellipse_outline(img, ex, eye_y, 38.0 * s, 48.0 * s, 2.5 * s, self.colors.outline);
-
-            // Spiral pattern
-            self.draw_smooth_circle(img, ex, eye_y, 22.0 * s, self.colors.eye_pupil);
-            self.draw_smooth_circle(img, ex, eye_y, 16.0 * s, self.colors.eye_white);
-            self.draw_smooth_circle(img, ex, eye_y, 10.0 * s, self.colors.eye_pupil);
-            self.draw_smooth_circle(img, ex, eye_y, 5.0 * s, self.colors.eye_white);
-        }
-
-        // Very raised eyebrows
-        self.draw_eyebrow(img, cx - eye_offset, eye_y - 65.0 * s, s, -0.4, 5.0);
-        self.draw_eyebrow(img, cx + eye_offset, eye_y - 65.0 * s, s, 0.4, 5.0);
-
-        // Nose
-        self.draw_nose(img, cx, cy + 18.0 * s, s);
-
-        // Huge open mouth
-        self.draw_ellipse_outline(img, cx, cy + 68.0 * s, 42.0 * s, 48.0 * s, 3.0 * s, self.colors.outline);
-        self.draw_smooth_ellipse(img, cx, cy + 68.0 * s, 42.0 * s, 48.0 * s, self.colors.mouth_dark);
-        self.draw_smooth_ellipse(img, cx, cy + 52.0 * s, 32.0 * s, 12.0 * s, self.colors.teeth);
-        self.draw_smooth_ellipse(img, cx, cy + 82.0 * s, 24.0 * s, 18.0 * s, self.colors.tongue);
+    #[test]
+    fn flatten_cubic_collapses_a_straight_segment_to_its_endpoint() {
+        // Control points sit exactly on the p0-p3 chord, so this is flat at
+        // depth 0 and should flatten straight to a single endpoint.
+        let mut out = Vec::new();
+        Character::flatten_cubic((0.0, 0.0), (2.0, 0.0), (4.0, 0.0), (6.0, 0.0), 0, &mut out);
+        assert_eq!(out, vec![(6.0, 0.0)]);
     }
 
-    fn draw_whispering_face(&self, img: &mut RgbaImage, cx: f32, cy: f32, s: f32) {
-        let eye_y = cy - 18.0 * s;
-        let eye_offset = 48.0 * s;
-
-        // Eyes looking to side
-        self.draw_eye(img, cx - eye_offset, eye_y, s, 24.0, 28.0, 9.0, 0.0);
-        self.draw_eye(img, cx + eye_offset, eye_y, s, 24.0, 28.0, 9.0, 0.0);
+    #[test]
+    fn flatten_cubic_subdivides_a_curved_segment_into_multiple_points() {
+        // Control points well off the chord force at least one De Casteljau
+        // split, so more than just the final endpoint should come out.
+        let mut out = Vec::new();
+        Character::flatten_cubic((0.0, 0.0), (0.0, 50.0), (20.0, 50.0), (20.0, 0.0), 0, &mut out);
+        assert!(out.len() > 1, "expected subdivision points for a sharply curved segment, got {out:?}");
+        assert_eq!(*out.last().unwrap(), (20.0, 0.0));
+    }
 
-        // Slightly raised eyebrows
-        self.draw_eyebrow(img, cx - eye_offset, eye_y - 40.0 * s, s, -0.12, 5.0);
-        self.draw_eyebrow(img, cx + eye_offset, eye_y - 40.0 * s, s, 0.12, 5.0);
+    #[test]
+    fn flatten_path_chains_segments_from_the_start_point() {
+        let segments = [BezierSegment { control1: (2.0, 0.0), control2: (4.0, 0.0), end: (6.0, 0.0) }];
+        let points = Character::flatten_path((0.0, 0.0), &segments);
+        assert_eq!(points.first(), Some(&(0.0, 0.0)));
+        assert_eq!(points.last(), Some(&(6.0, 0.0)));
+    }
 
-        // Nose
-        self.draw_nose(img, cx, cy + 18.0 * s, s);
+    #[test]
+    fn character_params_round_trip_through_json() {
+        let params = CharacterParams::builder().iris_scale(1.4).hair_blend(0.7).build();
+        let json = serde_json::to_string(&params).expect("serialize CharacterParams");
+        let restored: CharacterParams = serde_json::from_str(&json).expect("deserialize CharacterParams");
 
-        // Small pursed mouth
-        self.draw_smooth_ellipse(img, cx, cy + 58.0 * s, 14.0 * s, 12.0 * s, self.colors.mouth);
-        self.draw_ellipse_outline(img, cx, cy + 58.0 * s, 14.0 * s, 12.0 * s, 2.0 * s, self.colors.outline);
+        assert_eq!(restored.iris_scale, params.iris_scale);
+        assert_eq!(restored.hair_blend, params.hair_blend);
+        assert_eq!(restored.hair_spikes, params.hair_spikes);
     }
-}
 
-impl Default for Character {
-    fn default() -> Self {
-        Self::new()
+    #[test]
+    fn character_colors_round_trip_through_json() {
+        let colors = CharacterColors::default();
+        let json = serde_json::to_string(&colors).expect("serialize CharacterColors");
+        let restored: CharacterColors = serde_json::from_str(&json).expect("deserialize CharacterColors");
+
+        assert_eq!(restored.skin, colors.skin);
+        assert_eq!(restored.hair, colors.hair);
     }
 }
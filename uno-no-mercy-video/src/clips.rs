@@ -0,0 +1,226 @@
+//! Declarative animation clips
+//!
+//! `SceneManager::render_scene_*` dispatches by time with hand-written
+//! `if progress >= start && progress < end { ... }` blocks scattered through
+//! each routine. `Command` is a single timestamped beat (`ShowCard`,
+//! `MoveTo`, `FadeText`, `PlaySfxCue`, `Wait`) evaluated against the current
+//! time instead, and `Clip` groups a reusable sequence of them authored in
+//! clip-local time starting at 0.0 -- a card-draw animation, a stack-counter
+//! tick -- so it can be `spooled` into a scene at any start offset with its
+//! own parameters instead of being copy-pasted as bespoke control flow.
+
+use image::RgbaImage;
+
+use crate::cards::Card;
+use crate::effects::{Easing, Fade, Slide};
+use crate::text::{TextRenderer, TextStyle};
+use crate::video::FrameComposer;
+
+/// Where a `FadeText` command's label sits horizontally: a literal x, or
+/// centered within a canvas of the given width.
+#[derive(Clone)]
+pub enum TextX {
+    Fixed(i32),
+    CenteredIn(u32),
+}
+
+/// One timestamped beat in a clip, in clip-local time.
+#[derive(Clone)]
+pub enum Command {
+    /// Slide a card in from `from_x` to `to_x` at height `y`, scaled to
+    /// `scale`, over `window`.
+    ShowCard {
+        window: (f32, f32),
+        card: Card,
+        card_size: (u32, u32),
+        from_x: i32,
+        to_x: i32,
+        y: i32,
+        scale: f32,
+        easing: fn(f32) -> f32,
+    },
+    /// Interpolate an arbitrary sprite's position from `from` to `to` over
+    /// `window`.
+    MoveTo {
+        window: (f32, f32),
+        sprite: RgbaImage,
+        from: (i32, i32),
+        to: (i32, i32),
+        easing: fn(f32) -> f32,
+    },
+    /// Fade a text label in (or out) over `window`.
+    FadeText {
+        window: (f32, f32),
+        text: String,
+        style: TextStyle,
+        size: f32,
+        x: TextX,
+        y: i32,
+        fade_in: bool,
+    },
+    /// A marker for an external sound cue at `at`; this tool doesn't mix
+    /// audio itself, so it's recorded here for a future audio pass to read.
+    PlaySfxCue { at: f32, cue: String },
+    /// No visual -- just holds `window` open so later commands in the same
+    /// clip don't need their own offset arithmetic.
+    Wait { window: (f32, f32) },
+}
+
+impl Command {
+    fn shifted(self, offset: f32) -> Command {
+        match self {
+            Command::ShowCard { window, card, card_size, from_x, to_x, y, scale, easing } => Command::ShowCard {
+                window: (window.0 + offset, window.1 + offset),
+                card,
+                card_size,
+                from_x,
+                to_x,
+                y,
+                scale,
+                easing,
+            },
+            Command::MoveTo { window, sprite, from, to, easing } => {
+                Command::MoveTo { window: (window.0 + offset, window.1 + offset), sprite, from, to, easing }
+            }
+            Command::FadeText { window, text, style, size, x, y, fade_in } => Command::FadeText {
+                window: (window.0 + offset, window.1 + offset),
+                text,
+                style,
+                size,
+                x,
+                y,
+                fade_in,
+            },
+            Command::PlaySfxCue { at, cue } => Command::PlaySfxCue { at: at + offset, cue },
+            Command::Wait { window } => Command::Wait { window: (window.0 + offset, window.1 + offset) },
+        }
+    }
+
+    fn window(&self) -> Option<(f32, f32)> {
+        match self {
+            Command::ShowCard { window, .. } | Command::MoveTo { window, .. } | Command::FadeText { window, .. } | Command::Wait { window } => Some(*window),
+            Command::PlaySfxCue { .. } => None,
+        }
+    }
+
+    fn is_active(&self, time: f32) -> bool {
+        self.window().is_some_and(|(start, end)| time >= start && time < end)
+    }
+
+    fn render(&self, time: f32, composer: &FrameComposer, text_renderer: &TextRenderer, frame: &mut RgbaImage) {
+        match self {
+            Command::ShowCard { window, card, card_size, from_x, to_x, y, scale, easing } => {
+                let progress = window_progress(*window, time);
+                let card_img = card.render(card_size.0, card_size.1);
+                let scaled = FrameComposer::scale_image(&card_img, *scale);
+                let x = Slide::from_left(*from_x, *to_x, progress, *easing);
+                composer.composite(frame, &scaled, x, *y);
+            }
+            Command::MoveTo { window, sprite, from, to, easing } => {
+                let progress = easing(window_progress(*window, time));
+                let x = from.0 + ((to.0 - from.0) as f32 * progress) as i32;
+                let y = from.1 + ((to.1 - from.1) as f32 * progress) as i32;
+                composer.composite(frame, sprite, x, y);
+            }
+            Command::FadeText { window, text, style, size, x, y, fade_in } => {
+                let progress = window_progress(*window, time);
+                let alpha = if *fade_in { progress } else { 1.0 - progress };
+                let mut text_img = text_renderer.render(text, *size, style);
+                Fade::apply(&mut text_img, alpha);
+                let x = match x {
+                    TextX::Fixed(x) => *x,
+                    TextX::CenteredIn(canvas_width) => (*canvas_width as i32 - text_img.width() as i32) / 2,
+                };
+                composer.composite(frame, &text_img, x, *y);
+            }
+            Command::PlaySfxCue { .. } | Command::Wait { .. } => {}
+        }
+    }
+}
+
+fn window_progress(window: (f32, f32), time: f32) -> f32 {
+    ((time - window.0) / (window.1 - window.0).max(0.001)).clamp(0.0, 1.0)
+}
+
+/// Render every command in `commands` that's active at `time` into `frame`.
+pub fn render_active(commands: &[Command], time: f32, composer: &FrameComposer, text_renderer: &TextRenderer, frame: &mut RgbaImage) {
+    for command in commands {
+        if command.is_active(time) {
+            command.render(time, composer, text_renderer, frame);
+        }
+    }
+}
+
+/// A reusable sequence of `Command`s authored in clip-local time starting at
+/// 0.0, so the same clip can be spooled into any scene at any start offset.
+pub struct Clip {
+    commands: Vec<Command>,
+}
+
+impl Clip {
+    /// A card slides in from off-screen left to `(to_x, y)`, scaled to
+    /// `scale`, easing in over the whole `duration`. Its `label` fades in
+    /// while the card is still sliding, between 30% and 60% of `duration`,
+    /// and is drawn only within that span.
+    #[allow(clippy::too_many_arguments)]
+    pub fn card_draw(card: Card, card_size: (u32, u32), label: &str, to_x: i32, y: i32, scale: f32, duration: f32, label_x: TextX, label_y: i32) -> Self {
+        let slide_end = duration * 0.6;
+        Self {
+            commands: vec![
+                Command::ShowCard {
+                    window: (0.0, duration),
+                    card,
+                    card_size,
+                    from_x: -(card_size.0 as i32 * 2),
+                    to_x,
+                    y,
+                    scale,
+                    easing: Easing::ease_out,
+                },
+                Command::FadeText {
+                    window: (slide_end * 0.5, slide_end),
+                    text: label.to_string(),
+                    style: TextStyle::yellow_impact(),
+                    size: 100.0 * scale,
+                    x: label_x,
+                    y: label_y,
+                    fade_in: true,
+                },
+            ],
+        }
+    }
+
+    /// A stacking-math beat: an equation fades in, then swaps to a
+    /// resolution callout once the pile settles, both centered on a canvas
+    /// of `canvas_width`.
+    pub fn stack_counter_tick(equation: &str, resolution: &str, canvas_width: u32, y: i32, switch_at: f32, duration: f32) -> Self {
+        Self {
+            commands: vec![
+                Command::FadeText {
+                    window: (0.0, switch_at),
+                    text: equation.to_string(),
+                    style: TextStyle::white_with_black_outline(),
+                    size: 90.0,
+                    x: TextX::CenteredIn(canvas_width),
+                    y,
+                    fade_in: true,
+                },
+                Command::FadeText {
+                    window: (switch_at, duration),
+                    text: resolution.to_string(),
+                    style: TextStyle::red_bold(),
+                    size: 70.0,
+                    x: TextX::CenteredIn(canvas_width),
+                    y,
+                    fade_in: true,
+                },
+            ],
+        }
+    }
+
+    /// Shift every command's timing by `offset`, so the clip plays starting
+    /// at that point on the scene's own timeline instead of from zero.
+    pub fn spooled(&self, offset: f32) -> Vec<Command> {
+        self.commands.iter().cloned().map(|c| c.shifted(offset)).collect()
+    }
+}
@@ -0,0 +1,179 @@
+//! Beat/BPM music conductor
+//!
+//! Every animated beat in `scenes.rs` today is a fraction of scene
+//! `progress` (`progress * 25.0`, `progress > 0.8`...) with no relationship
+//! to the soundtrack. `Conductor` converts a song position in milliseconds
+//! into musical time -- `current_beat`, `current_step` (sixteenth notes),
+//! and `beat_fraction` -- so effects can fire on the beat instead of an
+//! arbitrary threshold. A sorted list of `(start_beat, bpm)` tempo sections
+//! lets a multi-tempo track still convert correctly: `ms_to_beat` walks the
+//! sections accumulating elapsed ms per section instead of assuming one
+//! constant bpm for the whole song. `SceneManager::with_conductor` attaches
+//! one; `render_frame` calls `advance` once per frame before any scene reads
+//! `on_beat`/`pulse`.
+
+use crate::effects::Easing;
+
+/// One tempo region: the song switches to `bpm` starting at `start_beat`
+/// (not a millisecond, so sections chain without re-deriving prior ones).
+#[derive(Debug, Clone, Copy)]
+pub struct TempoSection {
+    pub start_beat: f32,
+    pub bpm: f32,
+}
+
+/// Converts a playback position into musical time and derives beat-synced
+/// animation curves from it. Call `advance` once per frame before reading
+/// any other method.
+pub struct Conductor {
+    sections: Vec<TempoSection>,
+    current_beat: f32,
+    current_step: f32,
+    beat_fraction: f32,
+    last_whole_beat: Option<i64>,
+    on_beat: bool,
+}
+
+impl Conductor {
+    /// One constant tempo for the whole song.
+    pub fn new(bpm: f32) -> Self {
+        Self::with_sections(vec![TempoSection { start_beat: 0.0, bpm }])
+    }
+
+    /// A song with tempo changes; `sections` need not be pre-sorted.
+    pub fn with_sections(mut sections: Vec<TempoSection>) -> Self {
+        sections.sort_by(|a, b| a.start_beat.partial_cmp(&b.start_beat).unwrap_or(std::cmp::Ordering::Equal));
+        if sections.is_empty() {
+            sections.push(TempoSection { start_beat: 0.0, bpm: 120.0 });
+        }
+
+        Self {
+            sections,
+            current_beat: 0.0,
+            current_step: 0.0,
+            beat_fraction: 0.0,
+            last_whole_beat: None,
+            on_beat: false,
+        }
+    }
+
+    /// One beat's duration in milliseconds (a "crochet") at `bpm`.
+    fn crochet_ms(bpm: f32) -> f32 {
+        60_000.0 / bpm
+    }
+
+    /// Recompute `current_beat`/`current_step`/`beat_fraction`/`on_beat`
+    /// for `song_pos_ms`. `on_beat` is true only for the frame where the
+    /// integer beat first advances past the previous call's.
+    pub fn advance(&mut self, song_pos_ms: f32) {
+        self.current_beat = self.ms_to_beat(song_pos_ms);
+        self.current_step = self.current_beat * 4.0;
+        self.beat_fraction = self.current_beat.fract();
+
+        let whole_beat = self.current_beat.floor() as i64;
+        self.on_beat = match self.last_whole_beat {
+            Some(last) => whole_beat > last,
+            None => true,
+        };
+        self.last_whole_beat = Some(whole_beat);
+    }
+
+    /// Walk the sorted tempo sections, accumulating elapsed ms per section,
+    /// until `song_pos_ms` falls inside one, then convert the remainder
+    /// with that section's `crochet_ms`.
+    fn ms_to_beat(&self, song_pos_ms: f32) -> f32 {
+        let mut elapsed_at_start = 0.0;
+        let mut index = 0;
+
+        while index + 1 < self.sections.len() {
+            let current = self.sections[index];
+            let next = self.sections[index + 1];
+            let section_ms = (next.start_beat - current.start_beat) * Self::crochet_ms(current.bpm);
+
+            if song_pos_ms < elapsed_at_start + section_ms {
+                break;
+            }
+
+            elapsed_at_start += section_ms;
+            index += 1;
+        }
+
+        let section = self.sections[index];
+        section.start_beat + (song_pos_ms - elapsed_at_start) / Self::crochet_ms(section.bpm)
+    }
+
+    pub fn current_beat(&self) -> f32 {
+        self.current_beat
+    }
+
+    pub fn current_step(&self) -> f32 {
+        self.current_step
+    }
+
+    pub fn beat_fraction(&self) -> f32 {
+        self.beat_fraction
+    }
+
+    /// True for exactly one `advance` per integer beat.
+    pub fn on_beat(&self) -> bool {
+        self.on_beat
+    }
+
+    /// A bounce curve driven by `beat_fraction`: `scale_max` right on the
+    /// beat, decaying toward `scale_min` by the next one.
+    pub fn pulse(&self, scale_min: f32, scale_max: f32) -> f32 {
+        let decay = 1.0 - Easing::ease_out(self.beat_fraction);
+        scale_min + (scale_max - scale_min) * decay
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_tempo_converts_ms_to_beats() {
+        let mut conductor = Conductor::new(120.0);
+        // At 120 bpm a crochet is 500ms, so 1000ms should land on beat 2.
+        conductor.advance(1000.0);
+        assert!((conductor.current_beat() - 2.0).abs() < 1e-3);
+        assert!((conductor.current_step() - 8.0).abs() < 1e-3);
+        assert!(conductor.beat_fraction().abs() < 1e-3);
+    }
+
+    #[test]
+    fn on_beat_is_true_only_when_the_integer_beat_advances() {
+        let mut conductor = Conductor::new(120.0);
+        conductor.advance(0.0);
+        assert!(conductor.on_beat(), "first advance should count as on-beat");
+
+        conductor.advance(250.0);
+        assert!(!conductor.on_beat(), "still mid-beat");
+
+        conductor.advance(500.0);
+        assert!(conductor.on_beat(), "crossed into beat 1");
+    }
+
+    #[test]
+    fn tempo_sections_chain_without_recomputing_prior_ones() {
+        // Section 1 at 120bpm covers beats 0-4 (2000ms); section 2 kicks in
+        // at beat 4 at 240bpm (250ms/beat).
+        let mut conductor = Conductor::with_sections(vec![
+            TempoSection { start_beat: 0.0, bpm: 120.0 },
+            TempoSection { start_beat: 4.0, bpm: 240.0 },
+        ]);
+
+        conductor.advance(2000.0);
+        assert!((conductor.current_beat() - 4.0).abs() < 1e-3);
+
+        conductor.advance(2250.0);
+        assert!((conductor.current_beat() - 5.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn pulse_peaks_at_scale_max_on_the_beat() {
+        let mut conductor = Conductor::new(120.0);
+        conductor.advance(0.0);
+        assert!((conductor.pulse(1.0, 2.0) - 2.0).abs() < 1e-3);
+    }
+}
@@ -1,11 +1,20 @@
 //! Animation effects module
 //!
-//! Provides various visual effects for the video.
+//! Provides various visual effects for the video. `Glow` used to hardcode
+//! its own `blend_additive`/`blend_over` helpers instead of going through
+//! `blend::BlendMode`; it now composites with `BlendMode::Add` for the
+//! splat layers and `BlendMode::SrcOver` for the original image on top, the
+//! same machinery `cards.rs` uses for shadows and glow.
 
 use image::{Rgba, RgbaImage};
 use imageproc::drawing::{draw_filled_rect_mut};
 use imageproc::rect::Rect;
 
+use crate::blend::{self, BlendMode};
+use crate::brush::{Brush, Extend, GradientStop};
+use crate::raster;
+use crate::transform::sample_bilinear;
+
 /// Easing functions for animations
 pub struct Easing;
 
@@ -229,8 +238,10 @@ impl PopIn {
 pub struct Glow;
 
 impl Glow {
-    /// Add glow effect to an image
-    pub fn apply(img: &RgbaImage, glow_color: Rgba<u8>, radius: u32, intensity: f32) -> RgbaImage {
+    /// Add glow effect to an image. `gamma_correct` blends the additive
+    /// splat and the final composite in linear light instead of raw sRGB
+    /// bytes, for a physically correct (less muddy) glow.
+    pub fn apply(img: &RgbaImage, glow_color: Rgba<u8>, radius: u32, intensity: f32, gamma_correct: bool) -> RgbaImage {
         let width = img.width() + radius * 2;
         let height = img.height() + radius * 2;
         let mut result = RgbaImage::new(width, height);
@@ -256,7 +267,7 @@ impl Glow {
                                     let falloff = 1.0 - (dist / expand as f32);
                                     let a = (alpha as f32 * falloff) as u8;
                                     let current = result.get_pixel(px, py);
-                                    let blended = Self::blend_additive(*current, Rgba([glow_color[0], glow_color[1], glow_color[2], a]));
+                                    let blended = BlendMode::Add.blend_pixels_with(Rgba([glow_color[0], glow_color[1], glow_color[2], a]), *current, gamma_correct);
                                     result.put_pixel(px, py, blended);
                                 }
                             }
@@ -272,40 +283,13 @@ impl Glow {
                 let px = x + radius;
                 let py = y + radius;
                 let current = result.get_pixel(px, py);
-                let blended = Self::blend_over(*pixel, *current);
+                let blended = BlendMode::SrcOver.blend_pixels_with(*pixel, *current, gamma_correct);
                 result.put_pixel(px, py, blended);
             }
         }
 
         result
     }
-
-    fn blend_additive(base: Rgba<u8>, add: Rgba<u8>) -> Rgba<u8> {
-        let add_factor = add[3] as f32 / 255.0;
-        Rgba([
-            (base[0] as f32 + add[0] as f32 * add_factor).min(255.0) as u8,
-            (base[1] as f32 + add[1] as f32 * add_factor).min(255.0) as u8,
-            (base[2] as f32 + add[2] as f32 * add_factor).min(255.0) as u8,
-            base[3].max((add[3] as f32 * 0.5) as u8),
-        ])
-    }
-
-    fn blend_over(src: Rgba<u8>, dest: Rgba<u8>) -> Rgba<u8> {
-        let src_a = src[3] as f32 / 255.0;
-        let dest_a = dest[3] as f32 / 255.0;
-        let out_a = src_a + dest_a * (1.0 - src_a);
-
-        if out_a < 0.001 {
-            return Rgba([0, 0, 0, 0]);
-        }
-
-        Rgba([
-            ((src[0] as f32 * src_a + dest[0] as f32 * dest_a * (1.0 - src_a)) / out_a) as u8,
-            ((src[1] as f32 * src_a + dest[1] as f32 * dest_a * (1.0 - src_a)) / out_a) as u8,
-            ((src[2] as f32 * src_a + dest[2] as f32 * dest_a * (1.0 - src_a)) / out_a) as u8,
-            (out_a * 255.0) as u8,
-        ])
-    }
 }
 
 /// Particle system for floating effects
@@ -333,34 +317,7 @@ impl Particles {
     }
 
     fn draw_star(img: &mut RgbaImage, cx: f32, cy: f32, size: f32, color: Rgba<u8>) {
-        // Simple cross-shaped star
-        let x = cx as i32;
-        let y = cy as i32;
-        let s = size as i32;
-
-        for d in -s..=s {
-            // Horizontal
-            let px = (x + d).max(0) as u32;
-            let py = y.max(0) as u32;
-            if px < img.width() && py < img.height() {
-                let dist = d.abs() as f32 / s as f32;
-                let a = ((1.0 - dist) * color[3] as f32) as u8;
-                let current = img.get_pixel(px, py);
-                let blended = Glow::blend_additive(*current, Rgba([color[0], color[1], color[2], a]));
-                img.put_pixel(px, py, blended);
-            }
-
-            // Vertical
-            let px = x.max(0) as u32;
-            let py = (y + d).max(0) as u32;
-            if px < img.width() && py < img.height() {
-                let dist = d.abs() as f32 / s as f32;
-                let a = ((1.0 - dist) * color[3] as f32) as u8;
-                let current = img.get_pixel(px, py);
-                let blended = Glow::blend_additive(*current, Rgba([color[0], color[1], color[2], a]));
-                img.put_pixel(px, py, blended);
-            }
-        }
+        raster::draw_star(img, cx, cy, size, color, BlendMode::Add);
     }
 
     /// Generate energy wave effect
@@ -391,11 +348,172 @@ impl Particles {
     }
 }
 
+/// Which axis `Wiggle::apply` displaces rows/columns along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WiggleType {
+    /// Shift each row `y` horizontally by a sine of `y`.
+    Horizontal,
+    /// Shift each column `x` vertically by a sine of `x`.
+    Vertical,
+}
+
+/// Traveling sine-wave distortion ("liquid"/drunk text) for text and sprites
+pub struct Wiggle;
+
+impl Wiggle {
+    /// Apply a traveling sine distortion to `img`, growing the canvas by
+    /// `amplitude_px` on the displaced axis so the wave isn't clipped.
+    /// Samples through `transform::sample_bilinear`, the same clamped,
+    /// transparent-edge-aware bilinear sampler `composite_image_transformed`
+    /// uses, so sub-pixel offsets stay smooth at large font sizes.
+    pub fn apply(img: &RgbaImage, wiggle_type: WiggleType, amplitude_px: f32, wavelength_px: f32, speed: f32, time: f32) -> RgbaImage {
+        let pad = amplitude_px.abs().ceil() as u32;
+        let wavelength = wavelength_px.max(1e-3);
+
+        match wiggle_type {
+            WiggleType::Horizontal => {
+                let width = img.width() + pad * 2;
+                let height = img.height();
+                let mut result = RgbaImage::new(width, height);
+
+                for y in 0..height {
+                    let dx = amplitude_px * (std::f32::consts::TAU * (y as f32 / wavelength) + speed * time).sin();
+                    for x in 0..width {
+                        let src_x = x as f32 - pad as f32 - dx;
+                        if let Some(pixel) = sample_bilinear(img, src_x, y as f32) {
+                            result.put_pixel(x, y, pixel);
+                        }
+                    }
+                }
+
+                result
+            }
+            WiggleType::Vertical => {
+                let width = img.width();
+                let height = img.height() + pad * 2;
+                let mut result = RgbaImage::new(width, height);
+
+                for x in 0..width {
+                    let dy = amplitude_px * (std::f32::consts::TAU * (x as f32 / wavelength) + speed * time).sin();
+                    for y in 0..height {
+                        let src_y = y as f32 - pad as f32 - dy;
+                        if let Some(pixel) = sample_bilinear(img, x as f32, src_y) {
+                            result.put_pixel(x, y, pixel);
+                        }
+                    }
+                }
+
+                result
+            }
+        }
+    }
+}
+
+/// A whole-frame HSV color grade: a single cross-cutting knob in place of
+/// editing every `TextStyle`/background call a scene touches. `apply`
+/// mutates a composited `RgbaImage` in place; alpha is left untouched.
+#[derive(Debug, Clone, Copy)]
+pub struct ColorGrade {
+    /// Degrees added to each pixel's hue, wrapped into `[0, 360)`.
+    pub hue_shift: f32,
+    /// Multiplier on HSV saturation, clamped to `[0, 1]` after scaling.
+    pub saturation: f32,
+    /// Multiplier on HSV value (brightness), clamped to `[0, 1]` after scaling.
+    pub brightness: f32,
+}
+
+impl ColorGrade {
+    /// The no-op grade: zero hue shift, saturation and brightness unchanged.
+    pub fn neutral() -> Self {
+        Self { hue_shift: 0.0, saturation: 1.0, brightness: 1.0 }
+    }
+
+    /// Grade every pixel of `frame` in place: RGB -> HSV, shift hue and
+    /// scale saturation/value, then back to RGB. Alpha passes through.
+    pub fn apply(&self, frame: &mut RgbaImage) {
+        for pixel in frame.pixels_mut() {
+            let (h, s, v) = Self::rgb_to_hsv(pixel[0], pixel[1], pixel[2]);
+
+            let h = (h + self.hue_shift).rem_euclid(360.0);
+            let s = (s * self.saturation).clamp(0.0, 1.0);
+            let v = (v * self.brightness).clamp(0.0, 1.0);
+
+            let (r, g, b) = Self::hsv_to_rgb(h, s, v);
+            pixel[0] = r;
+            pixel[1] = g;
+            pixel[2] = b;
+        }
+    }
+
+    /// Linearly interpolate every field between `a` and `b`, for animating
+    /// a grade across a scene's local `progress`.
+    pub fn lerp(a: ColorGrade, b: ColorGrade, t: f32) -> ColorGrade {
+        let t = t.clamp(0.0, 1.0);
+        ColorGrade {
+            hue_shift: a.hue_shift + (b.hue_shift - a.hue_shift) * t,
+            saturation: a.saturation + (b.saturation - a.saturation) * t,
+            brightness: a.brightness + (b.brightness - a.brightness) * t,
+        }
+    }
+
+    fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (f32, f32, f32) {
+        let r = r as f32 / 255.0;
+        let g = g as f32 / 255.0;
+        let b = b as f32 / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        let h = if delta <= 1e-6 {
+            0.0
+        } else if max == r {
+            60.0 * (((g - b) / delta).rem_euclid(6.0))
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+
+        let s = if max <= 1e-6 { 0.0 } else { delta / max };
+        let v = max;
+
+        (h, s, v)
+    }
+
+    fn hsv_to_rgb(h: f32, s: f32, v: f32) -> (u8, u8, u8) {
+        let c = v * s;
+        let h_prime = h / 60.0;
+        let x = c * (1.0 - (h_prime.rem_euclid(2.0) - 1.0).abs());
+        let m = v - c;
+
+        let (r1, g1, b1) = if h_prime < 1.0 {
+            (c, x, 0.0)
+        } else if h_prime < 2.0 {
+            (x, c, 0.0)
+        } else if h_prime < 3.0 {
+            (0.0, c, x)
+        } else if h_prime < 4.0 {
+            (0.0, x, c)
+        } else if h_prime < 5.0 {
+            (x, 0.0, c)
+        } else {
+            (c, 0.0, x)
+        };
+
+        (
+            ((r1 + m) * 255.0).clamp(0.0, 255.0) as u8,
+            ((g1 + m) * 255.0).clamp(0.0, 255.0) as u8,
+            ((b1 + m) * 255.0).clamp(0.0, 255.0) as u8,
+        )
+    }
+}
+
 /// Color utilities
 pub struct ColorUtils;
 
 impl ColorUtils {
-    /// Interpolate between two colors
+    /// Interpolate between two colors directly in sRGB bytes.
     pub fn lerp(color1: Rgba<u8>, color2: Rgba<u8>, t: f32) -> Rgba<u8> {
         let t = t.clamp(0.0, 1.0);
         Rgba([
@@ -406,17 +524,34 @@ impl ColorUtils {
         ])
     }
 
-    /// Create a gradient
+    /// Interpolate between two colors in linear light instead of raw sRGB
+    /// bytes, so crossfades don't darken/muddy through the midpoint. Alpha
+    /// is already linear, so it's lerped directly like `lerp` does.
+    pub fn lerp_linear(color1: Rgba<u8>, color2: Rgba<u8>, t: f32) -> Rgba<u8> {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |c1: u8, c2: u8| -> u8 {
+            let a = blend::srgb_to_linear(c1);
+            let b = blend::srgb_to_linear(c2);
+            blend::linear_to_srgb(a + (b - a) * t)
+        };
+        Rgba([
+            mix(color1[0], color2[0]),
+            mix(color1[1], color2[1]),
+            mix(color1[2], color2[2]),
+            (color1[3] as f32 + (color2[3] as f32 - color1[3] as f32) * t) as u8,
+        ])
+    }
+
+    /// Create a top-to-bottom gradient. For more than two stops, or a
+    /// radial/focal sweep, build a `brush::Brush` directly instead.
     pub fn gradient(width: u32, height: u32, top: Rgba<u8>, bottom: Rgba<u8>) -> RgbaImage {
-        let mut img = RgbaImage::new(width, height);
-        for y in 0..height {
-            let t = y as f32 / height as f32;
-            let color = Self::lerp(top, bottom, t);
-            for x in 0..width {
-                img.put_pixel(x, y, color);
-            }
-        }
-        img
+        let brush = Brush::linear(
+            (0.0, 0.0),
+            (0.0, height as f32),
+            vec![GradientStop::new(0.0, top), GradientStop::new(1.0, bottom)],
+            Extend::Pad,
+        );
+        brush.fill(width, height)
     }
 
     /// Create a radial vignette
@@ -438,3 +573,53 @@ impl ColorUtils {
         img
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rgb_close(got: (u8, u8, u8), want: (u8, u8, u8)) {
+        let close = |a: u8, b: u8| (a as i16 - b as i16).abs() <= 2;
+        assert!(
+            close(got.0, want.0) && close(got.1, want.1) && close(got.2, want.2),
+            "got {got:?}, want {want:?}"
+        );
+    }
+
+    #[test]
+    fn rgb_hsv_round_trips_for_primary_colors() {
+        for rgb in [(255, 0, 0), (0, 255, 0), (0, 0, 255), (255, 255, 255), (0, 0, 0), (128, 64, 200)] {
+            let (h, s, v) = ColorGrade::rgb_to_hsv(rgb.0, rgb.1, rgb.2);
+            let back = ColorGrade::hsv_to_rgb(h, s, v);
+            assert_rgb_close(back, rgb);
+        }
+    }
+
+    #[test]
+    fn neutral_grade_leaves_a_frame_unchanged() {
+        let mut frame = RgbaImage::from_pixel(2, 2, Rgba([10, 120, 200, 255]));
+        let before = frame.clone();
+        ColorGrade::neutral().apply(&mut frame);
+        assert_rgb_close(
+            (frame.get_pixel(0, 0)[0], frame.get_pixel(0, 0)[1], frame.get_pixel(0, 0)[2]),
+            (before.get_pixel(0, 0)[0], before.get_pixel(0, 0)[1], before.get_pixel(0, 0)[2]),
+        );
+    }
+
+    #[test]
+    fn hue_shift_of_360_is_a_no_op() {
+        let grade = ColorGrade { hue_shift: 360.0, saturation: 1.0, brightness: 1.0 };
+        let mut frame = RgbaImage::from_pixel(1, 1, Rgba([200, 50, 80, 255]));
+        let before = frame.get_pixel(0, 0).0;
+        grade.apply(&mut frame);
+        assert_rgb_close((frame.get_pixel(0, 0)[0], frame.get_pixel(0, 0)[1], frame.get_pixel(0, 0)[2]), (before[0], before[1], before[2]));
+    }
+
+    #[test]
+    fn lerp_at_t_zero_and_one_returns_the_endpoints() {
+        let a = ColorGrade { hue_shift: 0.0, saturation: 1.0, brightness: 1.0 };
+        let b = ColorGrade { hue_shift: 90.0, saturation: 0.5, brightness: 0.2 };
+        assert_eq!(ColorGrade::lerp(a, b, 0.0).hue_shift, a.hue_shift);
+        assert_eq!(ColorGrade::lerp(a, b, 1.0).brightness, b.brightness);
+    }
+}
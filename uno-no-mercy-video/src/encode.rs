@@ -0,0 +1,99 @@
+//! FFmpeg encoder command generation
+//!
+//! Builds the ffmpeg invocation(s) `generate_ffmpeg_script` writes into
+//! `compile_video.sh`, from an `EncoderConfig` (codec choice, optional VAAPI
+//! hardware acceleration, and an optional two-pass intermediate) instead of
+//! the old hardcoded `libx264 -crf 23` + `aac`. Frame compositing always
+//! stays on raster PNGs; only encoding is ever offloaded to the GPU.
+
+use anyhow::{bail, Result};
+
+use crate::project::{AudioCodec, EncoderConfig, VideoCodec};
+
+const VAAPI_DEVICE: &str = "/dev/dri/renderD128";
+
+impl EncoderConfig {
+    /// `-c:v ...` args for the configured codec/hwaccel pair, erroring
+    /// instead of silently falling back if that combination has no encoder
+    /// wired up (AV1 over VAAPI isn't supported here yet).
+    fn video_codec_args(&self) -> Result<&'static str> {
+        Ok(match (self.video_codec, self.hwaccel) {
+            (VideoCodec::H264, false) => "-c:v libx264 -preset medium -crf 23",
+            (VideoCodec::Hevc, false) => "-c:v libx265 -preset medium -crf 26",
+            (VideoCodec::Av1, false) => "-c:v libsvtav1 -preset 7 -crf 28",
+            (VideoCodec::H264, true) => "-c:v h264_vaapi",
+            (VideoCodec::Hevc, true) => "-c:v hevc_vaapi",
+            (VideoCodec::Av1, true) => bail!(
+                "AV1 has no VAAPI encoder wired up; set hwaccel = false for video_codec = \"av1\""
+            ),
+        })
+    }
+
+    fn audio_codec_args(&self) -> &'static str {
+        match self.audio_codec {
+            AudioCodec::Aac => "-c:a aac -b:a 192k",
+            AudioCodec::Flac => "-c:a flac",
+        }
+    }
+
+    /// The `-vf` filter chain: subtitle burn-in always runs in software,
+    /// followed by the NV12 upload VAAPI encoders expect.
+    fn video_filter(&self, captions_path: &str) -> String {
+        let mut filters = vec![format!("subtitles={captions_path}")];
+        if self.hwaccel {
+            filters.push("format=nv12,hwupload".to_string());
+        }
+        filters.join(",")
+    }
+
+    /// One ffmpeg command when `two_pass_intermediate` is off, or two
+    /// (frames -> lossless-ish intermediate, then intermediate -> final)
+    /// when it's on, as shell fragments ready to drop into
+    /// `compile_video.sh`.
+    pub fn ffmpeg_commands(&self, fps: u32, captions_path: &str, output_path: &str) -> Result<Vec<String>> {
+        let video_args = self.video_codec_args()?;
+        let audio_args = self.audio_codec_args();
+        let filter = self.video_filter(captions_path);
+        let vaapi_device = if self.hwaccel { format!("-vaapi_device {VAAPI_DEVICE} ") } else { String::new() };
+
+        if !self.two_pass_intermediate {
+            return Ok(vec![format!(
+                "ffmpeg -y {vaapi_device}\\\n    \
+                 -framerate {fps} \\\n    \
+                 -i output/frames/frame_%05d.png \\\n    \
+                 -i output/audio/voiceover.mp3 \\\n    \
+                 -vf \"{filter}\" \\\n    \
+                 {video_args} \\\n    \
+                 -pix_fmt yuv420p \\\n    \
+                 {audio_args} \\\n    \
+                 -shortest \\\n    \
+                 -movflags +faststart \\\n    \
+                 {output_path}"
+            )]);
+        }
+
+        let intermediate = "output/intermediate.mkv";
+        let pass1 = format!(
+            "ffmpeg -y \\\n    \
+             -framerate {fps} \\\n    \
+             -i output/frames/frame_%05d.png \\\n    \
+             -i output/audio/voiceover.mp3 \\\n    \
+             -vf \"{filter}\" \\\n    \
+             -c:v libx264 -preset veryfast -crf 0 \\\n    \
+             -pix_fmt yuv420p \\\n    \
+             -c:a copy \\\n    \
+             -shortest \\\n    \
+             {intermediate}"
+        );
+        let pass2 = format!(
+            "ffmpeg -y {vaapi_device}\\\n    \
+             -i {intermediate} \\\n    \
+             {video_args} \\\n    \
+             -pix_fmt yuv420p \\\n    \
+             {audio_args} \\\n    \
+             -movflags +faststart \\\n    \
+             {output_path}"
+        );
+        Ok(vec![pass1, pass2])
+    }
+}
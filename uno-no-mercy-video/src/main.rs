@@ -1,30 +1,60 @@
 //! UNO No Mercy Animated Explainer Video Generator
 //!
-//! Generates a TikTok-style vertical video (9:16, 1080x1920) explaining UNO No Mercy rules.
-
+//! Generates a TikTok-style vertical video (9:16, 1080x1920) explaining UNO No Mercy rules,
+//! driven by a declarative `project.toml` describing the scene timeline and output.
+
+mod afterglow;
+mod animation;
+mod blend;
+mod bloom;
+mod brush;
+mod captions;
+mod conductor;
 mod character;
 mod cards;
+mod clips;
+mod compositor;
 mod effects;
+mod encode;
+mod motion_blur;
+mod particles;
+mod preview;
+mod profile;
+mod project;
+mod raster;
 mod scenes;
+mod script;
+mod shadow;
+mod shapes;
 mod text;
+mod timeline;
+mod transform;
+mod transitions;
 mod video;
+mod xorshift;
 
 use anyhow::Result;
 use indicatif::{ProgressBar, ProgressStyle};
 use std::path::Path;
 
-/// Video configuration
-pub const VIDEO_WIDTH: u32 = 1080;
-pub const VIDEO_HEIGHT: u32 = 1920;
-pub const FRAME_RATE: u32 = 30;
-pub const TOTAL_DURATION_SECS: f32 = 75.0;
+use captions::ScriptSegment;
+use project::ProjectConfig;
 
 fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().collect();
+    let show_stats = args.iter().any(|a| a == "--stats");
+
+    if let Some(time) = parse_preview_flag(&args) {
+        return run_preview(time, show_stats);
+    }
+
     println!("╔═══════════════════════════════════════════════════════════╗");
     println!("║       UNO NO MERCY - Animated Explainer Video Generator   ║");
     println!("╚═══════════════════════════════════════════════════════════╝");
     println!();
 
+    let config = ProjectConfig::load(Path::new("project.toml"))?;
+
     let output_dir = Path::new("output");
     let frames_dir = output_dir.join("frames");
 
@@ -32,18 +62,22 @@ fn main() -> Result<()> {
     std::fs::create_dir_all(&frames_dir)?;
     std::fs::create_dir_all(output_dir.join("audio"))?;
 
-    let total_frames = (TOTAL_DURATION_SECS * FRAME_RATE as f32) as u64;
+    let total_frames = (config.video.duration * config.video.fps as f32) as u64;
 
     println!("📊 Video Specifications:");
-    println!("   Resolution: {}x{} (9:16 vertical)", VIDEO_WIDTH, VIDEO_HEIGHT);
-    println!("   Frame Rate: {} fps", FRAME_RATE);
-    println!("   Duration: {:.1} seconds", TOTAL_DURATION_SECS);
+    println!("   Resolution: {}x{} (9:16 vertical)", config.video.width, config.video.height);
+    println!("   Frame Rate: {} fps", config.video.fps);
+    println!("   Duration: {:.1} seconds", config.video.duration);
     println!("   Total Frames: {}", total_frames);
     println!();
 
     // Generate the video script for TTS
     println!("📝 Generating voiceover script...");
-    generate_script_file(output_dir)?;
+    generate_script_file(output_dir, &config)?;
+
+    // Lay the same script out into timed karaoke captions
+    println!("💬 Generating caption track...");
+    generate_captions_file(output_dir, &config)?;
 
     // Generate all frames
     println!("🎬 Generating video frames...");
@@ -52,10 +86,11 @@ fn main() -> Result<()> {
         .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {pos}/{len} ({eta})")?
         .progress_chars("#>-"));
 
-    let scene_manager = scenes::SceneManager::new();
+    let mut scene_manager = build_scene_manager(&config)?;
+    scene_manager.set_stats_overlay(show_stats);
 
     for frame_num in 0..total_frames {
-        let time = frame_num as f32 / FRAME_RATE as f32;
+        let time = frame_num as f32 / config.video.fps as f32;
         let frame = scene_manager.render_frame(time, frame_num as u32)?;
 
         let frame_path = frames_dir.join(format!("frame_{:05}.png", frame_num));
@@ -69,7 +104,7 @@ fn main() -> Result<()> {
 
     // Generate FFmpeg command
     println!("🎥 Generating FFmpeg compilation script...");
-    generate_ffmpeg_script(output_dir)?;
+    generate_ffmpeg_script(output_dir, &config)?;
 
     println!();
     println!("✅ Frame generation complete!");
@@ -77,68 +112,120 @@ fn main() -> Result<()> {
     println!("📁 Output files:");
     println!("   Frames: output/frames/frame_*.png");
     println!("   Script: output/voiceover_script.txt");
+    println!("   Captions: output/captions.ass");
     println!("   FFmpeg: output/compile_video.sh");
     println!();
     println!("🎙️ Next steps:");
     println!("   1. Generate voiceover audio from output/voiceover_script.txt");
-    println!("      Use: edge-tts --text \"$(cat output/voiceover_script.txt)\" --voice en-US-GuyNeural --write-media output/audio/voiceover.mp3");
+    println!("      Use: edge-tts --text \"$(cat output/voiceover_script.txt)\" --voice {} --write-media output/audio/voiceover.mp3", config.voiceover.voice);
     println!("   2. Run: bash output/compile_video.sh");
     println!("   3. Final video: output/uno_no_mercy.mp4");
 
     Ok(())
 }
 
-fn generate_script_file(output_dir: &Path) -> Result<()> {
-    let script = r#"So you think you know UNO? Nah. Let me tell you about NO MERCY.
-
-168 cards. SIX players max. And if you get 25 cards in your hand? You're DEAD. Eliminated. Gone. That's the Mercy Rule and there IS no mercy.
-
-Plus 2? That's cute. Plus 4? Getting warmer. PLUS 10. And guess what? You can STACK them. Someone hits you with a plus 4? Throw down a plus 6. Now THEY draw 10. Unless they stack higher. It keeps going until someone CAN'T match it and draws EVERYTHING.
+/// Parse `--preview <time>` out of the raw argv, so one command can render
+/// a single frame to the terminal instead of the full sequence.
+fn parse_preview_flag(args: &[String]) -> Option<f32> {
+    let idx = args.iter().position(|a| a == "--preview")?;
+    args.get(idx + 1)?.parse().ok()
+}
 
-But here's what NO ONE tells you. That plus 4? It's NOT a wild card anymore. It has a COLOR. Red plus 4 only plays on RED. The wilds are Draw 6, Draw 10, Reverse Draw 4, and Color Roulette. THOSE play anytime.
+/// Render the frame at `time` from `project.toml` and print it straight
+/// into the terminal via `preview::show`, skipping PNG output entirely.
+/// `show_stats` draws the frame-time/FPS HUD (`--stats`) on top.
+fn run_preview(time: f32, show_stats: bool) -> Result<()> {
+    let config = ProjectConfig::load(Path::new("project.toml"))?;
+    let mut scene_manager = build_scene_manager(&config)?;
+    scene_manager.set_stats_overlay(show_stats);
+    let frame_num = (time * config.video.fps as f32) as u32;
+    let frame = scene_manager.render_frame(time, frame_num)?;
+    preview::show(&frame);
+    Ok(())
+}
 
-Oh you thought we were done? Play a 7, you SWAP your entire hand with someone. Play a 0, EVERYONE passes their hand to the next person. Skip Everyone? You skip THE WHOLE TABLE and go again. Discard All? Dump every card of that color at once. Color Roulette? They flip cards until they hit the color they call. Could be 2 cards. Could be 15.
+/// Build the `SceneManager` `project.toml` asks for: `scene_timeline` (if
+/// set) swaps the compiled-in `[[scene]]` dispatch for the flat `Timeline`
+/// event-list interpreter; otherwise `scene_script` swaps it for the
+/// `SceneScript` interpreter; otherwise the compiled-in dispatch runs as
+/// always.
+fn build_scene_manager(config: &ProjectConfig) -> Result<scenes::SceneManager> {
+    let mut scene_manager = if let Some(path) = &config.scene_timeline {
+        scenes::SceneManager::from_timeline(config, Path::new(path))?
+    } else if let Some(path) = &config.scene_script {
+        scenes::SceneManager::from_script(config, Path::new(path))?
+    } else {
+        scenes::SceneManager::from_config(config)
+    };
+
+    if let Some(seed) = config.seed {
+        scene_manager = scene_manager.with_seed(seed);
+    }
+    if let Some(bpm) = config.bpm {
+        scene_manager = scene_manager.with_conductor(conductor::Conductor::new(bpm));
+    }
+    if let Some(seed) = config.random_character_seed {
+        scene_manager = scene_manager.with_random_character(seed);
+    }
+    if let Some(iris_scale) = config.character_iris_scale {
+        scene_manager = scene_manager.with_character_iris_scale(iris_scale);
+    }
+    if let Some(bitmap_font) = &config.bitmap_font {
+        scene_manager = scene_manager.with_bitmap_font(
+            Path::new(&bitmap_font.sheet),
+            bitmap_font.glyph_width,
+            bitmap_font.glyph_height,
+            bitmap_font.first_char,
+        )?;
+    }
 
-And if you can't play? You don't just draw one card like a NORMAL person. You draw until you CAN play. No stopping. No passing. Just pain.
+    Ok(scene_manager)
+}
 
-This game has ended friendships. Ruined holidays. Created villains. Anyway, who wants to play?"#;
+fn generate_script_file(output_dir: &Path, config: &ProjectConfig) -> Result<()> {
+    std::fs::write(output_dir.join("voiceover_script.txt"), config.script())?;
+    Ok(())
+}
 
-    std::fs::write(output_dir.join("voiceover_script.txt"), script)?;
+fn generate_captions_file(output_dir: &Path, config: &ProjectConfig) -> Result<()> {
+    let segments: Vec<ScriptSegment> = config.scenes.iter()
+        .map(|s| ScriptSegment { start: s.start, end: s.end, text: &s.caption, style: &s.style })
+        .collect();
+
+    let lines = captions::layout_segments(&segments);
+    captions::write_ass_file(
+        &output_dir.join("captions.ass"),
+        &lines,
+        &captions::default_caption_styles(),
+        64.0,
+    )?;
     Ok(())
 }
 
-fn generate_ffmpeg_script(output_dir: &Path) -> Result<()> {
+fn generate_ffmpeg_script(output_dir: &Path, config: &ProjectConfig) -> Result<()> {
+    let ffmpeg_commands = config.encoder
+        .ffmpeg_commands(config.video.fps, "output/captions.ass", "output/uno_no_mercy.mp4")?
+        .join("\n\n");
+
     let script = format!(r#"#!/bin/bash
 # UNO No Mercy Video Compilation Script
 
 # Check if voiceover exists
 if [ ! -f "output/audio/voiceover.mp3" ]; then
-    echo "⚠️  No voiceover found. Generating with edge-tts..."
+    echo "⚠️  No voiceover found. Generating with {engine}..."
     edge-tts --text "$(cat output/voiceover_script.txt)" \
-             --voice en-US-GuyNeural \
-             --rate "+10%" \
+             --voice {voice} \
+             --rate "{rate}" \
              --write-media output/audio/voiceover.mp3
 fi
 
 # Compile frames to video with audio
-ffmpeg -y \
-    -framerate {fps} \
-    -i output/frames/frame_%05d.png \
-    -i output/audio/voiceover.mp3 \
-    -c:v libx264 \
-    -preset medium \
-    -crf 23 \
-    -pix_fmt yuv420p \
-    -c:a aac \
-    -b:a 192k \
-    -shortest \
-    -movflags +faststart \
-    output/uno_no_mercy.mp4
+{ffmpeg_commands}
 
 echo ""
 echo "✅ Video compiled successfully!"
 echo "📹 Output: output/uno_no_mercy.mp4"
-"#, fps = FRAME_RATE);
+"#, engine = config.voiceover.engine, voice = config.voiceover.voice, rate = config.voiceover.rate);
 
     let script_path = output_dir.join("compile_video.sh");
     std::fs::write(&script_path, script)?;
@@ -0,0 +1,240 @@
+//! Stateful particle engine
+//!
+//! `effects::Particles`'s `sparkles`/`energy_wave` recompute everything from
+//! `time`/`seed` with no persistence, so they can't express anything that
+//! actually evolves -- an explosion, rising embers, a directional burst.
+//! `Emitter` spawns `Particle`s at a configurable rate with randomized
+//! initial position, velocity, size, color and lifetime; `BurstEmitter`
+//! spawns a one-shot `number +/- number_deviation` batch instead, each a
+//! perturbed copy of a base `Particle`, for bursty effects an emission rate
+//! can't express cleanly. `ParticleSystem` owns the live set, integrates it
+//! each `step` (including per-particle `friction` drag), and `render`s it,
+//! reusing the cross-shaped star and additive blend `effects::Particles`
+//! already draws with. `SceneManager`'s chaos scene now drives one of
+//! these instead of calling `Particles::sparkles`/`energy_wave` directly.
+
+use image::{Rgba, RgbaImage};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::blend::BlendMode;
+use crate::raster;
+
+/// One live particle.
+#[derive(Debug, Clone)]
+pub struct Particle {
+    pub pos: (f32, f32),
+    pub vel: (f32, f32),
+    pub size: f32,
+    pub color: Rgba<u8>,
+    pub life: f32,
+    pub max_life: f32,
+    /// Per-step velocity drag: `vel *= 1 - friction * dt`. `0.0` matches
+    /// the old frictionless behavior.
+    pub friction: f32,
+}
+
+impl Particle {
+    /// Remaining life, normalized to `[0, 1]`.
+    fn remaining(&self) -> f32 {
+        (self.life / self.max_life).clamp(0.0, 1.0)
+    }
+}
+
+/// Spawns particles at `rate` per second with randomized initial state,
+/// all relative to a fixed spawn position.
+pub struct Emitter {
+    pub position: (f32, f32),
+    pub rate: f32,
+    pub velocity_min: (f32, f32),
+    pub velocity_max: (f32, f32),
+    pub size_range: (f32, f32),
+    pub color: Rgba<u8>,
+    pub color_jitter: u8,
+    pub lifetime_range: (f32, f32),
+    spawn_accumulator: f32,
+}
+
+impl Emitter {
+    pub fn new(
+        position: (f32, f32),
+        rate: f32,
+        velocity_min: (f32, f32),
+        velocity_max: (f32, f32),
+        size_range: (f32, f32),
+        color: Rgba<u8>,
+        color_jitter: u8,
+        lifetime_range: (f32, f32),
+    ) -> Self {
+        Self {
+            position,
+            rate,
+            velocity_min,
+            velocity_max,
+            size_range,
+            color,
+            color_jitter,
+            lifetime_range,
+            spawn_accumulator: 0.0,
+        }
+    }
+
+    fn jitter_channel(&self, rng: &mut StdRng, c: u8) -> u8 {
+        if self.color_jitter == 0 {
+            return c;
+        }
+        let delta = rng.gen_range(-(self.color_jitter as i16)..=self.color_jitter as i16);
+        (c as i16 + delta).clamp(0, 255) as u8
+    }
+
+    fn spawn(&self, rng: &mut StdRng) -> Particle {
+        let vel = (
+            rng.gen_range(self.velocity_min.0..self.velocity_max.0),
+            rng.gen_range(self.velocity_min.1..self.velocity_max.1),
+        );
+        let size = rng.gen_range(self.size_range.0..self.size_range.1);
+        let life = rng.gen_range(self.lifetime_range.0..self.lifetime_range.1);
+        let color = Rgba([
+            self.jitter_channel(rng, self.color[0]),
+            self.jitter_channel(rng, self.color[1]),
+            self.jitter_channel(rng, self.color[2]),
+            self.color[3],
+        ]);
+
+        Particle { pos: self.position, vel, size, color, life, max_life: life, friction: 0.0 }
+    }
+}
+
+/// A one-shot emission spec: spawn `number +/- number_deviation` particles,
+/// each a perturbed copy of `base`, for bursty effects (explosions, impact
+/// sparks) as opposed to `Emitter`'s steady per-second rate.
+pub struct BurstEmitter {
+    pub base: Particle,
+    pub position_deviation: (f32, f32),
+    pub velocity_deviation: (f32, f32),
+    pub duration_deviation: f32,
+    pub friction_deviation: f32,
+    pub number: u32,
+    pub number_deviation: u32,
+}
+
+impl BurstEmitter {
+    pub fn new(base: Particle, number: u32) -> Self {
+        Self {
+            base,
+            position_deviation: (0.0, 0.0),
+            velocity_deviation: (0.0, 0.0),
+            duration_deviation: 0.0,
+            friction_deviation: 0.0,
+            number,
+            number_deviation: 0,
+        }
+    }
+
+    fn perturb(rng: &mut StdRng, value: f32, deviation: f32) -> f32 {
+        if deviation <= 0.0 {
+            value
+        } else {
+            value + rng.gen_range(-deviation..=deviation)
+        }
+    }
+
+    /// Compute `final_count = max(0, number +/- number_deviation)`, then
+    /// spawn that many particles, each with `base`'s position, velocity,
+    /// duration and friction perturbed by `rand_float(-dev, dev)` (duration
+    /// and friction clamped to >= 0); size and color are copied unchanged.
+    pub fn emit(&self, rng: &mut StdRng) -> Vec<Particle> {
+        let count = if self.number_deviation == 0 {
+            self.number
+        } else {
+            let spread = self.number_deviation as i32;
+            let delta = rng.gen_range(-spread..=spread);
+            (self.number as i32 + delta).max(0) as u32
+        };
+
+        (0..count).map(|_| {
+            let pos = (
+                Self::perturb(rng, self.base.pos.0, self.position_deviation.0),
+                Self::perturb(rng, self.base.pos.1, self.position_deviation.1),
+            );
+            let vel = (
+                Self::perturb(rng, self.base.vel.0, self.velocity_deviation.0),
+                Self::perturb(rng, self.base.vel.1, self.velocity_deviation.1),
+            );
+            let life = Self::perturb(rng, self.base.max_life, self.duration_deviation).max(0.0);
+            let friction = Self::perturb(rng, self.base.friction, self.friction_deviation).max(0.0);
+
+            Particle { pos, vel, size: self.base.size, color: self.base.color, life, max_life: life, friction }
+        }).collect()
+    }
+}
+
+/// Owns the live particle set, advancing and rendering it frame to frame.
+pub struct ParticleSystem {
+    particles: Vec<Particle>,
+    gravity: (f32, f32),
+    rng: StdRng,
+}
+
+impl ParticleSystem {
+    pub fn new(gravity: (f32, f32), seed: u64) -> Self {
+        Self { particles: Vec::new(), gravity, rng: StdRng::seed_from_u64(seed) }
+    }
+
+    /// Spawn from each emitter, integrate `pos += vel*dt; vel += gravity*dt;
+    /// life -= dt`, then drop anything whose life ran out.
+    pub fn step(&mut self, dt: f32, emitters: &mut [Emitter]) {
+        for emitter in emitters.iter_mut() {
+            emitter.spawn_accumulator += emitter.rate * dt;
+            while emitter.spawn_accumulator >= 1.0 {
+                emitter.spawn_accumulator -= 1.0;
+                self.particles.push(emitter.spawn(&mut self.rng));
+            }
+        }
+
+        for particle in self.particles.iter_mut() {
+            particle.pos.0 += particle.vel.0 * dt;
+            particle.pos.1 += particle.vel.1 * dt;
+            particle.vel.0 += self.gravity.0 * dt;
+            particle.vel.1 += self.gravity.1 * dt;
+            let drag = (1.0 - particle.friction * dt).max(0.0);
+            particle.vel.0 *= drag;
+            particle.vel.1 *= drag;
+            particle.life -= dt;
+        }
+
+        self.particles.retain(|p| p.life > 0.0);
+    }
+
+    /// Spawn a `BurstEmitter`'s particles into the live set, using this
+    /// system's own seeded RNG so a whole render stays reproducible.
+    pub fn spawn_burst(&mut self, burst: &BurstEmitter) {
+        self.particles.extend(burst.emit(&mut self.rng));
+    }
+
+    pub fn particle_count(&self) -> usize {
+        self.particles.len()
+    }
+
+    /// Draw every live particle as a star, fading alpha and size over its
+    /// remaining normalized life.
+    pub fn render(&self, width: u32, height: u32) -> RgbaImage {
+        let mut img = RgbaImage::new(width, height);
+        for particle in &self.particles {
+            let t = particle.remaining();
+            let alpha = (particle.color[3] as f32 * t) as u8;
+            let size = particle.size * t;
+            Self::draw_star(&mut img, particle.pos.0, particle.pos.1, size, Rgba([
+                particle.color[0],
+                particle.color[1],
+                particle.color[2],
+                alpha,
+            ]));
+        }
+        img
+    }
+
+    fn draw_star(img: &mut RgbaImage, cx: f32, cy: f32, size: f32, color: Rgba<u8>) {
+        raster::draw_star(img, cx, cy, size, color, BlendMode::Add);
+    }
+}
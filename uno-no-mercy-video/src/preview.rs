@@ -0,0 +1,157 @@
+//! Terminal frame preview
+//!
+//! Renders a single frame and prints it directly into the terminal instead
+//! of writing a full PNG sequence, so a `TextStyle` or effect timing tweak
+//! can be eyeballed with one `--preview <time>` run instead of the whole
+//! 75-second generation loop.
+
+use base64::Engine;
+use image::{imageops::FilterType, RgbaImage};
+
+/// Which inline-image escape-sequence protocol the host terminal
+/// understands, detected from environment variables rather than probed.
+enum TerminalProtocol {
+    Kitty,
+    Sixel,
+}
+
+fn detect_terminal() -> TerminalProtocol {
+    let term = std::env::var("TERM").unwrap_or_default();
+    if std::env::var("KITTY_WINDOW_ID").is_ok() || term.contains("kitty") {
+        TerminalProtocol::Kitty
+    } else {
+        TerminalProtocol::Sixel
+    }
+}
+
+/// How many pixels the terminal has to show a preview in, derived from its
+/// reported character grid. Most terminal fonts land close to an 8x16px
+/// cell; without a pixel-accurate ioctl report (many ptys don't set one)
+/// that's the best guess available, and reserves two rows for the prompt.
+fn terminal_pixel_bounds() -> (u32, u32) {
+    const CELL_WIDTH_PX: u32 = 8;
+    const CELL_HEIGHT_PX: u32 = 16;
+
+    let (cols, rows) = terminal_size::terminal_size()
+        .map(|(terminal_size::Width(w), terminal_size::Height(h))| (w as u32, h as u32))
+        .unwrap_or((80, 24));
+
+    (cols * CELL_WIDTH_PX, rows.saturating_sub(2) * CELL_HEIGHT_PX)
+}
+
+/// Downscale `frame` to fit within the terminal's reported cell grid,
+/// preserving aspect ratio. Never upscales.
+fn fit_to_terminal(frame: &RgbaImage) -> RgbaImage {
+    let (max_w, max_h) = terminal_pixel_bounds();
+    if frame.width() <= max_w && frame.height() <= max_h {
+        return frame.clone();
+    }
+
+    let scale = (max_w as f32 / frame.width() as f32).min(max_h as f32 / frame.height() as f32);
+    let width = ((frame.width() as f32 * scale) as u32).max(1);
+    let height = ((frame.height() as f32 * scale) as u32).max(1);
+    image::imageops::resize(frame, width, height, FilterType::Lanczos3)
+}
+
+/// Print `frame` directly into the terminal using whichever inline-image
+/// protocol was detected.
+pub fn show(frame: &RgbaImage) {
+    let scaled = fit_to_terminal(frame);
+    match detect_terminal() {
+        TerminalProtocol::Kitty => print_kitty(&scaled),
+        TerminalProtocol::Sixel => print_sixel(&scaled),
+    }
+}
+
+/// Kitty graphics protocol: base64-encoded raw RGBA (`f=32`), chunked into
+/// <=4096-byte payloads across `\x1b_G...\x1b\\` escapes per the protocol's
+/// per-chunk limit, with `m=1`/`m=0` marking all but the last chunk. `a=T`
+/// on the first chunk both transmits and displays the image -- omitting it
+/// defaults to `a=t` (transmit-only), which uploads the frame into the
+/// terminal's image cache but never actually shows it.
+fn print_kitty(frame: &RgbaImage) {
+    const CHUNK_SIZE: usize = 4096;
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(frame.as_raw());
+    let chunks: Vec<&[u8]> = encoded.as_bytes().chunks(CHUNK_SIZE).collect();
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = if i + 1 < chunks.len() { 1 } else { 0 };
+        let payload = std::str::from_utf8(chunk).expect("base64 output is ASCII");
+        if i == 0 {
+            print!("\x1b_Ga=T,f=32,s={},v={},m={};{}\x1b\\", frame.width(), frame.height(), more, payload);
+        } else {
+            print!("\x1b_Gm={};{}\x1b\\", more, payload);
+        }
+    }
+    println!();
+}
+
+/// The 6x6x6 "web safe" color cube sixel terminals are quantized to, since
+/// they don't decode truecolor directly.
+const LEVELS: [u8; 6] = [0, 51, 102, 153, 204, 255];
+
+fn nearest_level_index(c: u8) -> usize {
+    (0..LEVELS.len())
+        .min_by_key(|&i| (LEVELS[i] as i32 - c as i32).abs())
+        .unwrap()
+}
+
+fn palette_index(r: u8, g: u8, b: u8) -> usize {
+    nearest_level_index(r) * 36 + nearest_level_index(g) * 6 + nearest_level_index(b)
+}
+
+/// Encode `frame` as a DEC sixel image: a palette of all 216 cube colors
+/// (`#n;2;r;g;b`, in ffmpeg/sixel's 0-100 percent scale) followed by one
+/// 6-pixel-tall band per row group, each band emitting one sixel run per
+/// color that appears in it.
+fn print_sixel(frame: &RgbaImage) {
+    let mut out = String::from("\x1bPq");
+
+    for (idx, levels) in LEVELS.iter().enumerate().flat_map(|(ri, &r)| {
+        LEVELS.iter().enumerate().flat_map(move |(gi, &g)| {
+            LEVELS.iter().enumerate().map(move |(bi, &b)| (ri * 36 + gi * 6 + bi, (r, g, b)))
+        })
+    }) {
+        let (r, g, b) = levels;
+        out.push_str(&format!(
+            "#{idx};2;{};{};{}",
+            r as u32 * 100 / 255,
+            g as u32 * 100 / 255,
+            b as u32 * 100 / 255,
+        ));
+    }
+
+    let (width, height) = frame.dimensions();
+    for band_y in (0..height).step_by(6) {
+        let band_height = (height - band_y).min(6);
+
+        for color_idx in 0..LEVELS.len().pow(3) {
+            let mut run = String::new();
+            let mut used = false;
+
+            for x in 0..width {
+                let mut bits = 0u8;
+                for dy in 0..band_height {
+                    let p = frame.get_pixel(x, band_y + dy);
+                    if palette_index(p[0], p[1], p[2]) == color_idx {
+                        bits |= 1 << dy;
+                        used = true;
+                    }
+                }
+                run.push((0x3f + bits) as char);
+            }
+
+            if used {
+                out.push_str(&format!("#{color_idx}"));
+                out.push_str(&run);
+                out.push('$');
+            }
+        }
+        out.push('-');
+    }
+
+    out.push_str("\x1b\\");
+    print!("{out}");
+    println!();
+}
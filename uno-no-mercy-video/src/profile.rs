@@ -0,0 +1,117 @@
+//! Per-frame render profiling
+//!
+//! Tuning the heavy compositing in scenes 1 and 5 meant guessing which
+//! effect was expensive. `RenderProfiler` keeps a fixed-size sliding window
+//! of recent `render_frame` durations and derives the current frame time,
+//! min, max, and FPS over that window; `render_stats_overlay` draws those
+//! numbers as a small HUD panel so authors can see which scenes are
+//! expensive as effect counts grow, toggled by a flag on `SceneManager`.
+//! `StatsSnapshot` is cheap to clone so a headless encode loop can log it
+//! even with the on-screen overlay disabled.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use image::{Rgba, RgbaImage};
+use imageproc::drawing::draw_filled_rect_mut;
+use imageproc::rect::Rect;
+
+use crate::blend::BlendMode;
+use crate::text::{TextRenderer, TextStyle};
+
+/// How many recent frames `RenderProfiler` keeps.
+const WINDOW: usize = 100;
+
+/// Current/min/max frame time and derived FPS over the trailing window.
+#[derive(Debug, Clone, Copy)]
+pub struct StatsSnapshot {
+    pub frame_time: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub fps: f32,
+}
+
+/// A fixed-size sliding window of recent `render_frame` durations.
+pub struct RenderProfiler {
+    samples: VecDeque<Duration>,
+    last: Option<StatsSnapshot>,
+}
+
+impl RenderProfiler {
+    pub fn new() -> Self {
+        Self { samples: VecDeque::with_capacity(WINDOW), last: None }
+    }
+
+    /// Push this frame's render time, popping the oldest once the window
+    /// exceeds `WINDOW`, and return the resulting snapshot.
+    pub fn record(&mut self, elapsed: Duration) -> StatsSnapshot {
+        self.samples.push_back(elapsed);
+        if self.samples.len() > WINDOW {
+            self.samples.pop_front();
+        }
+
+        let min = self.samples.iter().min().copied().unwrap_or(elapsed);
+        let max = self.samples.iter().max().copied().unwrap_or(elapsed);
+        let total: Duration = self.samples.iter().sum();
+        let avg = total / self.samples.len().max(1) as u32;
+        let fps = if avg.as_secs_f32() > 0.0 { 1.0 / avg.as_secs_f32() } else { 0.0 };
+
+        let snapshot = StatsSnapshot { frame_time: elapsed, min, max, fps };
+        self.last = Some(snapshot);
+        snapshot
+    }
+
+    /// The most recent snapshot recorded by `record`, if any.
+    pub fn last(&self) -> Option<StatsSnapshot> {
+        self.last
+    }
+}
+
+impl Default for RenderProfiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Draw a semi-transparent black panel with frame-time/min-max/FPS labels
+/// in the top-left corner of `frame`.
+pub fn render_stats_overlay(frame: &mut RgbaImage, snapshot: StatsSnapshot, text_renderer: &TextRenderer) {
+    let panel_x = 20;
+    let panel_y = 20;
+    let panel_w = 280;
+    let panel_h = 100;
+
+    draw_filled_rect_mut(frame, Rect::at(panel_x, panel_y).of_size(panel_w, panel_h), Rgba([0, 0, 0, 160]));
+
+    let style = TextStyle::white_with_black_outline();
+    let lines = [
+        format!("Frame: {:.2} ms", snapshot.frame_time.as_secs_f64() * 1000.0),
+        format!("min/max: {:.2}/{:.2} ms", snapshot.min.as_secs_f64() * 1000.0, snapshot.max.as_secs_f64() * 1000.0),
+        format!("FPS: {:.1}", snapshot.fps),
+    ];
+
+    for (i, line) in lines.iter().enumerate() {
+        let text_img = text_renderer.render(line, 22.0, &style);
+        blit(frame, &text_img, panel_x + 12, panel_y + 8 + i as i32 * 28);
+    }
+}
+
+/// A plain source-over blit, reusing `blend::BlendMode` instead of a third
+/// copy of the alpha-combine math `video::FrameComposer` already has.
+fn blit(frame: &mut RgbaImage, layer: &RgbaImage, x: i32, y: i32) {
+    for (lx, ly, pixel) in layer.enumerate_pixels() {
+        let fx = x + lx as i32;
+        let fy = y + ly as i32;
+
+        if fx < 0 || fy < 0 || fx as u32 >= frame.width() || fy as u32 >= frame.height() {
+            continue;
+        }
+        if pixel[3] == 0 {
+            continue;
+        }
+
+        let dest = frame.get_pixel(fx as u32, fy as u32);
+        let blended = BlendMode::SrcOver.blend_pixels(*pixel, *dest);
+        frame.put_pixel(fx as u32, fy as u32, blended);
+    }
+}
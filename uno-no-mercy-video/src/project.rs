@@ -0,0 +1,201 @@
+//! Declarative project configuration
+//!
+//! Loads a `project.toml` describing the output spec, the ordered scene
+//! timeline, and voiceover generation, so reusing this tool for a different
+//! explainer video is a config edit instead of a recompile.
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// `[video]` — output dimensions, frame rate, and total duration.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VideoConfig {
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    pub duration: f32,
+}
+
+/// Which hardcoded `SceneManager::render_scene_*` routine a `[[scene]]`
+/// entry dispatches to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SceneKind {
+    Hook,
+    Basics,
+    DrawCards,
+    PlotTwist,
+    Chaos,
+    GoldenRule,
+    Outro,
+}
+
+fn default_style() -> String {
+    "Default".to_string()
+}
+
+/// How `SceneManager` blends from the previous scene into this one. `Cut`
+/// (the old, only behavior) is a hard cut with no blend window.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionKind {
+    Cut,
+    CrossFade,
+    FadeThroughBlack,
+    WipeLeft,
+    WipeRight,
+    WipeUp,
+    WipeDown,
+    Iris,
+    Dissolve,
+}
+
+fn default_transition() -> TransitionKind {
+    TransitionKind::Cut
+}
+
+/// `[bitmap_font]` -- a sprite-sheet font `SceneManager::with_bitmap_font`
+/// registers under `FontId::Bitmap`, letting the scene 2 skull callout opt
+/// into a chunky pixel/retro look instead of the bundled Roboto TTF.
+/// Omitted leaves `FontId::Bitmap` falling back to `FontId::Default`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct BitmapFontConfig {
+    pub sheet: String,
+    pub glyph_width: u32,
+    pub glyph_height: u32,
+    #[serde(default = "default_first_char")]
+    pub first_char: char,
+}
+
+fn default_first_char() -> char {
+    ' '
+}
+
+/// One entry in the `[[scene]]` timeline: which routine to run, the window
+/// of video time it owns, the line of narration/caption it contributes, and
+/// the knobs that routine reads out of `effects` instead of a hardcoded
+/// literal (e.g. `sparkle_count`, `shake_intensity`).
+#[derive(Debug, Clone, Deserialize)]
+pub struct SceneConfig {
+    pub kind: SceneKind,
+    pub start: f32,
+    pub end: f32,
+    pub caption: String,
+    #[serde(default = "default_style")]
+    pub style: String,
+    #[serde(default)]
+    pub effects: HashMap<String, f32>,
+    /// How this scene blends in from the one before it.
+    #[serde(default = "default_transition")]
+    pub transition: TransitionKind,
+    /// Length in seconds of the blend window at this scene's `start`; `0.0`
+    /// (the default) means a hard cut regardless of `transition`.
+    #[serde(default)]
+    pub transition_duration: f32,
+}
+
+/// `[voiceover]` — the TTS engine/voice/rate baked into the generated
+/// `compile_video.sh`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct VoiceoverConfig {
+    pub engine: String,
+    pub voice: String,
+    pub rate: String,
+}
+
+/// A video codec `EncoderConfig::ffmpeg_commands` can target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VideoCodec {
+    H264,
+    Hevc,
+    Av1,
+}
+
+/// An audio codec `EncoderConfig::ffmpeg_commands` can target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioCodec {
+    Aac,
+    Flac,
+}
+
+/// `[encoder]` — the codec pair the final render is encoded with. Required
+/// rather than defaulted, so picking `libx264` is always a decision made in
+/// `project.toml`, not a silent fallback.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EncoderConfig {
+    pub video_codec: VideoCodec,
+    pub audio_codec: AudioCodec,
+    /// Encode on the GPU via VAAPI instead of the matching software encoder.
+    #[serde(default)]
+    pub hwaccel: bool,
+    /// Burn frames+subtitles into a fast near-lossless intermediate first,
+    /// then transcode that to the final codec, so re-running just the final
+    /// (often slow, e.g. AV1) pass doesn't redo frame decode + subtitle
+    /// rendering.
+    #[serde(default)]
+    pub two_pass_intermediate: bool,
+}
+
+/// The full `project.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectConfig {
+    pub video: VideoConfig,
+    /// The compiled-in scene dispatch, read when neither `scene_script` nor
+    /// `scene_timeline` is set.
+    #[serde(rename = "scene", default)]
+    pub scenes: Vec<SceneConfig>,
+    pub voiceover: VoiceoverConfig,
+    pub encoder: EncoderConfig,
+    /// Path to a `SceneScript` TOML file (`script::SceneScript`) to drive
+    /// scene composition instead of the compiled-in `[[scene]]` dispatch.
+    /// Ignored if `scene_timeline` is also set.
+    #[serde(default)]
+    pub scene_script: Option<String>,
+    /// Path to a plain-text `Timeline` event file (`timeline::Timeline`) to
+    /// drive scene composition. Takes precedence over `scene_script`.
+    #[serde(default)]
+    pub scene_timeline: Option<String>,
+    /// Master seed `SceneManager::with_seed` reseeds the whole render from,
+    /// so two renders with the same seed reproduce every sparkle, wave, and
+    /// chaos-background offset bit-for-bit. Omitted keeps the old hardcoded
+    /// seed.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Soundtrack tempo `SceneManager::with_conductor` builds a `Conductor`
+    /// from, so the scene 6/7 throbs land on the beat. Omitted leaves those
+    /// throbs at their flat fallback multiplier.
+    #[serde(default)]
+    pub bpm: Option<f32>,
+    /// Seed `SceneManager::with_random_character` rolls a procedurally
+    /// generated host (`character::CharacterParams::random`) from, instead
+    /// of the hardcoded default geometry. Omitted keeps the default look.
+    #[serde(default)]
+    pub random_character_seed: Option<u64>,
+    /// Iris scale `SceneManager::with_character_iris_scale` builds a
+    /// one-off `CharacterParams` from via `CharacterParams::builder`.
+    /// Takes precedence over `random_character_seed` if both are set.
+    #[serde(default)]
+    pub character_iris_scale: Option<f32>,
+    /// Sprite-sheet font `SceneManager::with_bitmap_font` loads and
+    /// registers under `FontId::Bitmap`. Omitted keeps the TTF-only look.
+    #[serde(default)]
+    pub bitmap_font: Option<BitmapFontConfig>,
+}
+
+impl ProjectConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading project file {}", path.display()))?;
+        toml::from_str(&text).with_context(|| format!("parsing project file {}", path.display()))
+    }
+
+    /// The full voiceover script: every scene's caption, in timeline order,
+    /// as its own paragraph.
+    pub fn script(&self) -> String {
+        self.scenes.iter().map(|s| s.caption.as_str()).collect::<Vec<_>>().join("\n\n")
+    }
+}
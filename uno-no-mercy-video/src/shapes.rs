@@ -0,0 +1,123 @@
+//! Shader-style procedural backgrounds and anti-aliased shape primitives
+//!
+//! `Backgrounds` (in `video.rs`) and `TitleCard::render` (in `text.rs`) each
+//! hand-roll their own `for y { for x { ... } }` pixel loop to fill a frame.
+//! `fill` replaces that boilerplate with a single per-pixel evaluator
+//! `Fn(x, y, time) -> Rgba<u8>`, so a scene can describe a background as one
+//! small closure instead of a bespoke loop, and the shader can read `time`
+//! to pulse in sync with the beats `AnimatedText::shake_offset` rides on.
+//! `draw_filled_circle`/`draw_ring` round out the toolkit with anti-aliased
+//! disc/annulus primitives for card-flip and "Color Roulette" spinner
+//! visuals.
+
+use image::{Rgba, RgbaImage};
+
+/// Fill a new `width`x`height` frame by evaluating `shader` at every
+/// pixel's integer `(x, y)` coordinate, passing it the current `time`.
+pub fn fill(width: u32, height: u32, time: f32, shader: impl Fn(f32, f32, f32) -> Rgba<u8>) -> RgbaImage {
+    let mut img = RgbaImage::new(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            img.put_pixel(x, y, shader(x as f32, y as f32, time));
+        }
+    }
+    img
+}
+
+/// A radial gradient from `inner` at `center` out to `outer` at `max_dist`.
+pub fn radial_gradient(center: (f32, f32), max_dist: f32, inner: Rgba<u8>, outer: Rgba<u8>) -> impl Fn(f32, f32, f32) -> Rgba<u8> {
+    move |x, y, _time| {
+        let dist = ((x - center.0).powi(2) + (y - center.1).powi(2)).sqrt();
+        let t = (dist / max_dist).clamp(0.0, 1.0);
+        lerp_color(inner, outer, t)
+    }
+}
+
+/// Cycles through `colors` over `period_secs`, sampling a smooth lerp
+/// between whichever pair of colors `time` currently falls between.
+pub fn color_cycle(colors: &[Rgba<u8>], period_secs: f32) -> impl Fn(f32, f32, f32) -> Rgba<u8> + '_ {
+    move |_x, _y, time| {
+        if colors.is_empty() {
+            return Rgba([0, 0, 0, 255]);
+        }
+        let phase = (time / period_secs.max(0.001)).rem_euclid(colors.len() as f32);
+        let i = phase as usize % colors.len();
+        let j = (i + 1) % colors.len();
+        lerp_color(colors[i], colors[j], phase.fract())
+    }
+}
+
+fn lerp_color(a: Rgba<u8>, b: Rgba<u8>, t: f32) -> Rgba<u8> {
+    Rgba([
+        (a[0] as f32 + (b[0] as f32 - a[0] as f32) * t) as u8,
+        (a[1] as f32 + (b[1] as f32 - a[1] as f32) * t) as u8,
+        (a[2] as f32 + (b[2] as f32 - a[2] as f32) * t) as u8,
+        (a[3] as f32 + (b[3] as f32 - a[3] as f32) * t) as u8,
+    ])
+}
+
+/// Paint a filled circle at `(cx, cy)` with `radius`, anti-aliasing the
+/// boundary with coverage: a pixel whose center falls within half a pixel
+/// of the edge gets `color` blended in proportionally rather than an
+/// all-or-nothing distance test.
+pub fn draw_filled_circle(img: &mut RgbaImage, cx: f32, cy: f32, radius: f32, color: Rgba<u8>) {
+    for_each_pixel_in_bounds(img, cx, cy, radius, |img, x, y| {
+        let dist = pixel_center_distance(x, y, cx, cy);
+        let coverage = (radius + 0.5 - dist).clamp(0.0, 1.0);
+        if coverage > 0.0 {
+            blend_pixel(img, x, y, coverage, color);
+        }
+    });
+}
+
+/// Paint an annulus between `inner_radius` and `outer_radius`, anti-aliasing
+/// both edges the same way `draw_filled_circle` does.
+pub fn draw_ring(img: &mut RgbaImage, cx: f32, cy: f32, inner_radius: f32, outer_radius: f32, color: Rgba<u8>) {
+    for_each_pixel_in_bounds(img, cx, cy, outer_radius, |img, x, y| {
+        let dist = pixel_center_distance(x, y, cx, cy);
+        let outer_coverage = (outer_radius + 0.5 - dist).clamp(0.0, 1.0);
+        let inner_coverage = (dist - (inner_radius - 0.5)).clamp(0.0, 1.0);
+        let coverage = outer_coverage.min(inner_coverage);
+        if coverage > 0.0 {
+            blend_pixel(img, x, y, coverage, color);
+        }
+    });
+}
+
+fn pixel_center_distance(x: u32, y: u32, cx: f32, cy: f32) -> f32 {
+    ((x as f32 + 0.5 - cx).powi(2) + (y as f32 + 0.5 - cy).powi(2)).sqrt()
+}
+
+/// Walk the square bounding box around `(cx, cy)` out to `radius` (clamped
+/// to the image), which is the midpoint-circle-algorithm trick of only
+/// testing pixels that could possibly be inside the shape.
+fn for_each_pixel_in_bounds(img: &mut RgbaImage, cx: f32, cy: f32, radius: f32, mut visit: impl FnMut(&mut RgbaImage, u32, u32)) {
+    let x_min = (cx - radius - 1.0).floor().max(0.0) as u32;
+    let x_max = ((cx + radius + 1.0).ceil() as u32).min(img.width());
+    let y_min = (cy - radius - 1.0).floor().max(0.0) as u32;
+    let y_max = ((cy + radius + 1.0).ceil() as u32).min(img.height());
+
+    for y in y_min..y_max {
+        for x in x_min..x_max {
+            visit(img, x, y);
+        }
+    }
+}
+
+/// Standard alpha-over blend of `color` into the pixel at `(x, y)`, scaled
+/// by `coverage` (the fraction of the pixel the shape covers).
+fn blend_pixel(img: &mut RgbaImage, x: u32, y: u32, coverage: f32, color: Rgba<u8>) {
+    let src_alpha = (color[3] as f32 / 255.0) * coverage;
+    if src_alpha <= 0.0 {
+        return;
+    }
+    let dest = *img.get_pixel(x, y);
+    let dest_alpha = dest[3] as f32 / 255.0;
+    let out_alpha = src_alpha + dest_alpha * (1.0 - src_alpha);
+    if out_alpha <= 0.0 {
+        return;
+    }
+
+    let blend = |src: u8, dst: u8| ((src as f32 * src_alpha + dst as f32 * dest_alpha * (1.0 - src_alpha)) / out_alpha) as u8;
+    img.put_pixel(x, y, Rgba([blend(color[0], dest[0]), blend(color[1], dest[1]), blend(color[2], dest[2]), (out_alpha * 255.0) as u8]));
+}
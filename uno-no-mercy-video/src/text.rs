@@ -1,11 +1,64 @@
 //! Text rendering module
 //!
-//! Handles text overlays and animated text effects.
-
-use ab_glyph::{Font, FontRef, PxScale, ScaleFont};
+//! Handles text overlays and animated text effects. Glyph rasterization is
+//! pluggable: `Font` is the contract (`measure`, `line_height`,
+//! `render_glyphs`) that `TextRenderer`'s outline/shadow/glow pipeline
+//! builds on, and `FontId` is the key a `TextStyle` uses to pick which
+//! registered `Font` renders it. `TtfFont` wraps the original
+//! HarfBuzz-shaped, ab_glyph-rasterized TTF/OTF path (still the bundled
+//! Roboto by default, or a fontconfig-resolved family like "Impact"), and
+//! `BitmapFont` reads a fixed-grid sprite sheet for a chunky pixel/retro
+//! face instead of a scalable outline.
+
+use ab_glyph::{point, Font as AbGlyphFont, FontRef, GlyphId, PxScale, ScaleFont};
+use anyhow::{Context, Result};
+use harfbuzz_rs::{shape as hb_shape, Face as HbFace, Font as HbFont, UnicodeBuffer};
 use image::{Rgba, RgbaImage};
-use imageproc::drawing::{draw_filled_rect_mut, draw_text_mut};
+use imageproc::drawing::draw_filled_rect_mut;
 use imageproc::rect::Rect;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Key into `TextRenderer`'s font registry. A `TextStyle` carries one of
+/// these instead of baking in a face, so the same outline/shadow pipeline
+/// can render through a real TTF or a bitmap sprite sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FontId {
+    /// The bundled Roboto Bold TTF -- the only face before this existed.
+    Default,
+    /// A system "Impact"-style TTF resolved via fontconfig at registry
+    /// construction time, falling back to `Default` if none is found.
+    Impact,
+    /// A fixed-grid sprite-sheet bitmap font for a chunky pixel/retro look.
+    /// Nothing is registered under this id unless a scene attaches one with
+    /// `TextRenderer::register_font`, so it also falls back to `Default`.
+    Bitmap,
+}
+
+/// Glyph rasterization contract. `TextRenderer::render` calls
+/// `render_glyphs` once per outline offset and once each for the shadow and
+/// main pass, so any `Font` automatically gets the existing outline/shadow
+/// pipeline for free.
+pub trait Font {
+    /// The `(width, height)` a run of `text` occupies at `size`, unpadded.
+    fn measure(&self, text: &str, size: f32) -> (f32, f32);
+
+    /// This font's line height at `size`, for layout math across lines.
+    fn line_height(&self, size: f32) -> f32;
+
+    /// Alpha-blend `text` at `size` into `img`, tinted `color`, with the
+    /// top-left corner of its line box at `(pen_x, pen_y)`.
+    fn render_glyphs(&self, img: &mut RgbaImage, text: &str, size: f32, pen_x: f32, pen_y: f32, color: Rgba<u8>);
+
+    /// Byte offsets of shaped-cluster boundaries (including `text.len()`),
+    /// for reveal effects like `AnimatedText::typewriter_progress`. Fonts
+    /// with no shaping step fall back to one boundary per `char`.
+    fn shape_clusters(&self, text: &str) -> Vec<usize> {
+        let mut clusters: Vec<usize> = (0..=text.len()).filter(|i| text.is_char_boundary(*i)).collect();
+        clusters.dedup();
+        clusters
+    }
+}
 
 /// Text style configuration
 #[derive(Clone)]
@@ -16,6 +69,8 @@ pub struct TextStyle {
     pub shadow: bool,
     pub shadow_offset: (i32, i32),
     pub shadow_color: Rgba<u8>,
+    /// Which registered `Font` rasterizes this style's glyphs.
+    pub font: FontId,
 }
 
 impl Default for TextStyle {
@@ -27,6 +82,7 @@ impl Default for TextStyle {
             shadow: true,
             shadow_offset: (4, 4),
             shadow_color: Rgba([0, 0, 0, 180]),
+            font: FontId::Default,
         }
     }
 }
@@ -44,9 +100,12 @@ impl TextStyle {
             shadow: true,
             shadow_offset: (5, 5),
             shadow_color: Rgba([0, 0, 0, 200]),
+            font: FontId::Default,
         }
     }
 
+    /// The shoutiest preset, now actually backed by a real Impact-like TTF
+    /// (`FontId::Impact`) instead of just leaning on outline/shadow weight.
     pub fn yellow_impact() -> Self {
         Self {
             color: Rgba([255, 230, 50, 255]),
@@ -55,6 +114,7 @@ impl TextStyle {
             shadow: true,
             shadow_offset: (6, 6),
             shadow_color: Rgba([0, 0, 0, 220]),
+            font: FontId::Impact,
         }
     }
 
@@ -66,66 +126,361 @@ impl TextStyle {
             shadow: false,
             shadow_offset: (0, 0),
             shadow_color: Rgba([0, 0, 0, 0]),
+            font: FontId::Default,
         }
     }
 }
 
-/// Text renderer with various effects
-pub struct TextRenderer {
-    font_data: &'static [u8],
+/// Where a `TtfFont`'s face bytes come from: the font bundled with the
+/// crate, or one resolved from the host's installed fonts via fontconfig
+/// (which can't be `'static` since it's read from disk at runtime).
+enum FontSource {
+    Embedded(&'static [u8]),
+    Owned(Vec<u8>),
 }
 
-impl TextRenderer {
-    pub fn new() -> Self {
-        let font_data: &'static [u8] = include_bytes!("../fonts/Roboto-Bold.ttf");
+impl FontSource {
+    fn bytes(&self) -> &[u8] {
+        match self {
+            FontSource::Embedded(b) => b,
+            FontSource::Owned(v) => v,
+        }
+    }
+}
+
+/// One glyph positioned by HarfBuzz shaping, already converted from the
+/// 26.6 fixed-point units HarfBuzz reports into pixels.
+struct ShapedGlyph {
+    glyph_id: GlyphId,
+    x_offset: f32,
+    y_offset: f32,
+    x_advance: f32,
+    y_advance: f32,
+}
+
+/// A shaped run of text: positioned glyphs, the pen's total advance/line
+/// metrics, and cluster byte-boundaries so reveal effects (the typewriter)
+/// can cut on whole shaped clusters instead of raw chars.
+struct ShapedText {
+    glyphs: Vec<ShapedGlyph>,
+    width: f32,
+    height: f32,
+    ascent: f32,
+    clusters: Vec<usize>,
+}
+
+/// A scalable TTF/OTF face, shaped with HarfBuzz and rasterized with
+/// ab_glyph's outliner -- the renderer this crate always used, now behind
+/// the `Font` trait instead of hardcoded into `TextRenderer`.
+pub struct TtfFont {
+    font_data: FontSource,
+}
+
+impl TtfFont {
+    /// The font bundled with the crate (`fonts/Roboto-Bold.ttf`).
+    pub fn embedded() -> Self {
+        Self { font_data: FontSource::Embedded(include_bytes!("../fonts/Roboto-Bold.ttf")) }
+    }
+
+    /// Resolve a system font by family name (e.g. `"Impact"`) via
+    /// fontconfig, falling back to the bundled Roboto if the family can't
+    /// be found or fontconfig isn't available on the host.
+    pub fn from_family(family: &str) -> Self {
+        let font_data = Self::resolve_family(family)
+            .map(FontSource::Owned)
+            .unwrap_or_else(|| FontSource::Embedded(include_bytes!("../fonts/Roboto-Bold.ttf")));
         Self { font_data }
     }
 
+    fn resolve_family(family: &str) -> Option<Vec<u8>> {
+        let fc = fontconfig::Fontconfig::new()?;
+        let font = fc.find(family, None)?;
+        std::fs::read(&font.path).ok()
+    }
+
     fn get_font(&self) -> FontRef<'_> {
-        FontRef::try_from_slice(self.font_data).expect("Failed to load font")
+        FontRef::try_from_slice(self.font_data.bytes()).expect("Failed to load font")
     }
 
-    /// Calculate text dimensions
-    fn text_dimensions(&self, text: &str, size: f32) -> (u32, u32) {
+    /// Shape `text` against the loaded face with HarfBuzz, producing
+    /// per-glyph positions (kerning, ligatures, and non-Latin/combining
+    /// scripts all fall out of this instead of a naive per-char advance
+    /// sum) plus the cluster map reveal effects need.
+    fn shape(&self, text: &str, size: f32) -> ShapedText {
         let font = self.get_font();
-        let scale = PxScale::from(size);
-        let scaled_font = font.as_scaled(scale);
+        let scaled_font = font.as_scaled(PxScale::from(size));
+        let ascent = scaled_font.ascent();
+        let height = scaled_font.height();
 
+        let hb_face = HbFace::new(self.font_data.bytes(), 0);
+        let mut hb_font = HbFont::new(hb_face);
+        let scale_26_6 = (size * 64.0).round() as i32;
+        hb_font.set_scale(scale_26_6, scale_26_6);
+
+        let buffer = UnicodeBuffer::new().add_str(text);
+        let output = hb_shape(&hb_font, buffer, &[]);
+        let infos = output.get_glyph_infos();
+        let positions = output.get_glyph_positions();
+
+        let mut glyphs = Vec::with_capacity(infos.len());
+        let mut clusters = Vec::new();
         let mut width = 0.0f32;
-        for c in text.chars() {
-            width += scaled_font.h_advance(scaled_font.glyph_id(c));
+
+        for (info, pos) in infos.iter().zip(positions.iter()) {
+            clusters.push(info.cluster as usize);
+            glyphs.push(ShapedGlyph {
+                glyph_id: GlyphId(info.codepoint as u16),
+                x_offset: pos.x_offset as f32 / 64.0,
+                y_offset: pos.y_offset as f32 / 64.0,
+                x_advance: pos.x_advance as f32 / 64.0,
+                y_advance: pos.y_advance as f32 / 64.0,
+            });
+            width += pos.x_advance as f32 / 64.0;
         }
 
-        let height = scaled_font.height();
-        (width.ceil() as u32, height.ceil() as u32)
+        clusters.push(text.len());
+        clusters.sort_unstable();
+        clusters.dedup();
+
+        ShapedText { glyphs, width, height, ascent, clusters }
     }
 
-    /// Render text with style to a new image
-    pub fn render(&self, text: &str, size: f32, style: &TextStyle) -> RgbaImage {
+    /// Rasterize every glyph in `shaped` with ab_glyph's outliner, walking
+    /// the pen across each glyph's shaped advance/offset and alpha-blending
+    /// its coverage into `img` at `(pen_x, pen_y)` in `color`, `pen_y` being
+    /// the baseline.
+    fn draw_shaped(&self, img: &mut RgbaImage, shaped: &ShapedText, font: &FontRef<'_>,
+                   scale: PxScale, pen_x: f32, pen_y: f32, color: Rgba<u8>) {
+        let mut cursor_x = pen_x;
+        let mut cursor_y = pen_y;
+
+        for g in &shaped.glyphs {
+            let position = point(cursor_x + g.x_offset, cursor_y - g.y_offset);
+            if let Some(outlined) = font.outline_glyph(g.glyph_id.with_scale_and_position(scale, position)) {
+                let bounds = outlined.px_bounds();
+                outlined.draw(|gx, gy, coverage| {
+                    let x = bounds.min.x + gx as f32;
+                    let y = bounds.min.y + gy as f32;
+                    if x >= 0.0 && y >= 0.0 {
+                        let alpha = (color[3] as f32 * coverage) as u8;
+                        blend_pixel(img, x as u32, y as u32, Rgba([color[0], color[1], color[2], alpha]));
+                    }
+                });
+            }
+            cursor_x += g.x_advance;
+            cursor_y -= g.y_advance;
+        }
+    }
+}
+
+impl Font for TtfFont {
+    fn measure(&self, text: &str, size: f32) -> (f32, f32) {
+        let shaped = self.shape(text, size);
+        (shaped.width, shaped.height)
+    }
+
+    fn line_height(&self, size: f32) -> f32 {
+        self.get_font().as_scaled(PxScale::from(size)).height()
+    }
+
+    fn render_glyphs(&self, img: &mut RgbaImage, text: &str, size: f32, pen_x: f32, pen_y: f32, color: Rgba<u8>) {
         let font = self.get_font();
         let scale = PxScale::from(size);
+        let shaped = self.shape(text, size);
+        self.draw_shaped(img, &shaped, &font, scale, pen_x, pen_y + shaped.ascent, color);
+    }
+
+    fn shape_clusters(&self, text: &str) -> Vec<usize> {
+        self.shape(text, 64.0).clusters
+    }
+}
+
+/// A fixed-grid sprite-sheet bitmap font: `sheet` holds one glyph per cell
+/// in a `glyph_width` x `glyph_height` grid, ordered left-to-right then
+/// top-to-bottom starting at `first_char`'s code point -- the common retro
+/// bitmap-font layout. Every glyph advances by the same cell width (no
+/// per-glyph kerning), and the sheet's RGB is ignored: only its alpha
+/// channel is used as coverage, recolored to whatever `TextStyle::color`
+/// asks for, the same way `TtfFont`'s outline coverage is tinted.
+pub struct BitmapFont {
+    sheet: RgbaImage,
+    glyph_width: u32,
+    glyph_height: u32,
+    columns: u32,
+    first_char: char,
+}
+
+impl BitmapFont {
+    /// Load a sprite sheet from `path`. `first_char` is the code point of
+    /// the sheet's top-left cell (e.g. `' '` for the common from-space
+    /// ASCII layout).
+    pub fn load(path: &Path, glyph_width: u32, glyph_height: u32, first_char: char) -> Result<Self> {
+        let sheet = image::open(path)
+            .with_context(|| format!("loading bitmap font sheet {}", path.display()))?
+            .to_rgba8();
+        let columns = (sheet.width() / glyph_width.max(1)).max(1);
+        Ok(Self { sheet, glyph_width, glyph_height, columns, first_char })
+    }
 
-        // Calculate text dimensions
-        let (width, height) = self.text_dimensions(text, size);
+    fn glyph_cell(&self, ch: char) -> Option<(u32, u32)> {
+        let index = (ch as u32).checked_sub(self.first_char as u32)?;
+        let col = index % self.columns;
+        let row = index / self.columns;
+        if (row + 1) * self.glyph_height > self.sheet.height() {
+            return None;
+        }
+        Some((col * self.glyph_width, row * self.glyph_height))
+    }
+}
+
+impl Font for BitmapFont {
+    fn measure(&self, text: &str, size: f32) -> (f32, f32) {
+        let scale = size / self.glyph_height.max(1) as f32;
+        (text.chars().count() as f32 * self.glyph_width as f32 * scale, self.glyph_height as f32 * scale)
+    }
+
+    fn line_height(&self, size: f32) -> f32 {
+        size
+    }
+
+    fn render_glyphs(&self, img: &mut RgbaImage, text: &str, size: f32, pen_x: f32, pen_y: f32, color: Rgba<u8>) {
+        let scale = size / self.glyph_height.max(1) as f32;
+        let mut cursor_x = pen_x;
+
+        for ch in text.chars() {
+            if let Some((sx, sy)) = self.glyph_cell(ch) {
+                for gy in 0..self.glyph_height {
+                    for gx in 0..self.glyph_width {
+                        let src = self.sheet.get_pixel(sx + gx, sy + gy);
+                        let coverage = src[3] as f32 / 255.0;
+                        if coverage <= 0.0 {
+                            continue;
+                        }
+
+                        // Nearest-neighbor upscale keeps the pixel/retro
+                        // look crisp instead of smoothing it away.
+                        let x0 = (cursor_x + gx as f32 * scale).round() as i32;
+                        let y0 = (pen_y + gy as f32 * scale).round() as i32;
+                        let x1 = (cursor_x + (gx + 1) as f32 * scale).round() as i32;
+                        let y1 = (pen_y + (gy + 1) as f32 * scale).round() as i32;
+
+                        for y in y0..y1.max(y0 + 1) {
+                            for x in x0..x1.max(x0 + 1) {
+                                if x >= 0 && y >= 0 {
+                                    let alpha = (color[3] as f32 * coverage) as u8;
+                                    blend_pixel(img, x as u32, y as u32, Rgba([color[0], color[1], color[2], alpha]));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            cursor_x += self.glyph_width as f32 * scale;
+        }
+    }
+}
+
+/// Alpha-blend `src` onto `img` at `(x, y)`, skipping fully transparent
+/// sources and out-of-bounds destinations. Shared by every `Font`
+/// implementor so they all composite glyph coverage identically.
+fn blend_pixel(img: &mut RgbaImage, x: u32, y: u32, src: Rgba<u8>) {
+    if x >= img.width() || y >= img.height() {
+        return;
+    }
+
+    let src_a = src[3] as f32 / 255.0;
+    if src_a < 0.001 {
+        return;
+    }
+
+    let dest = img.get_pixel(x, y);
+    let dest_a = dest[3] as f32 / 255.0;
+    let out_a = src_a + dest_a * (1.0 - src_a);
+    if out_a < 0.001 {
+        return;
+    }
+
+    let r = (src[0] as f32 * src_a + dest[0] as f32 * dest_a * (1.0 - src_a)) / out_a;
+    let g = (src[1] as f32 * src_a + dest[1] as f32 * dest_a * (1.0 - src_a)) / out_a;
+    let b = (src[2] as f32 * src_a + dest[2] as f32 * dest_a * (1.0 - src_a)) / out_a;
+
+    img.put_pixel(x, y, Rgba([r as u8, g as u8, b as u8, (out_a * 255.0) as u8]));
+}
+
+/// Text renderer with various effects. Holds a registry of `Font`
+/// implementors keyed by `FontId`, so one style can render through a real
+/// TTF and another through a bitmap sprite sheet without either knowing
+/// about the other.
+pub struct TextRenderer {
+    fonts: HashMap<FontId, Box<dyn Font>>,
+}
+
+impl TextRenderer {
+    pub fn new() -> Self {
+        let mut fonts: HashMap<FontId, Box<dyn Font>> = HashMap::new();
+        fonts.insert(FontId::Default, Box::new(TtfFont::embedded()));
+        fonts.insert(FontId::Impact, Box::new(TtfFont::from_family("Impact")));
+        Self { fonts }
+    }
+
+    /// A renderer whose `FontId::Default` is a system font resolved by
+    /// family name instead of the bundled Roboto (see `TtfFont::from_family`).
+    pub fn from_family(family: &str) -> Self {
+        let mut renderer = Self::new();
+        renderer.fonts.insert(FontId::Default, Box::new(TtfFont::from_family(family)));
+        renderer
+    }
+
+    /// Register (or replace) the `Font` rendered for `id`, e.g. attaching a
+    /// real sprite-sheet `BitmapFont` under `FontId::Bitmap` once one is
+    /// available.
+    pub fn register_font(&mut self, id: FontId, font: Box<dyn Font>) {
+        self.fonts.insert(id, font);
+    }
+
+    /// Look up `id`'s registered font, falling back to `FontId::Default`
+    /// for any id nothing has been registered under (mirroring
+    /// `TtfFont::from_family`'s own fallback-when-unavailable behavior).
+    fn font(&self, id: FontId) -> &dyn Font {
+        self.fonts.get(&id)
+            .or_else(|| self.fonts.get(&FontId::Default))
+            .expect("FontId::Default must always be registered")
+            .as_ref()
+    }
+
+    /// Byte offsets of each shaped-cluster boundary `font` would produce
+    /// for `text` (including a trailing boundary at `text.len()`), for
+    /// callers like `AnimatedText::typewriter_progress` that need to reveal
+    /// whole clusters rather than splitting a ligature or combining mark.
+    pub fn shape_clusters(&self, font: FontId, text: &str) -> Vec<usize> {
+        self.font(font).shape_clusters(text)
+    }
+
+    /// Calculate text dimensions
+    fn text_dimensions(&self, font: FontId, text: &str, size: f32) -> (u32, u32) {
+        let (w, h) = self.font(font).measure(text, size);
+        (w.ceil() as u32, h.ceil() as u32)
+    }
+
+    /// Render text with style to a new image
+    pub fn render(&self, text: &str, size: f32, style: &TextStyle) -> RgbaImage {
+        let font = self.font(style.font);
+        let (width, height) = font.measure(text, size);
 
         // Add padding for outline and shadow
         let padding = style.outline_width + style.shadow_offset.0.unsigned_abs().max(style.shadow_offset.1.unsigned_abs());
-        let img_width = width + padding * 2 + 10; // Extra padding for safety
-        let img_height = height + padding * 2 + 10;
+        let img_width = width.ceil() as u32 + padding * 2 + 10; // Extra padding for safety
+        let img_height = height.ceil() as u32 + padding * 2 + 10;
 
         let mut img = RgbaImage::new(img_width, img_height);
+        let pen_y = padding as f32;
 
         // Draw shadow
         if style.shadow {
-            draw_text_mut(
-                &mut img,
-                style.shadow_color,
-                padding as i32 + style.shadow_offset.0,
-                padding as i32 + style.shadow_offset.1,
-                scale,
-                &font,
-                text,
-            );
+            font.render_glyphs(&mut img, text, size,
+                padding as f32 + style.shadow_offset.0 as f32,
+                pen_y + style.shadow_offset.1 as f32,
+                style.shadow_color);
         }
 
         // Draw outline (by drawing text multiple times offset)
@@ -137,29 +492,16 @@ impl TextRenderer {
 
             for w in 1..=style.outline_width as i32 {
                 for (ox, oy) in &offsets {
-                    draw_text_mut(
-                        &mut img,
-                        outline_color,
-                        padding as i32 + ox * w,
-                        padding as i32 + oy * w,
-                        scale,
-                        &font,
-                        text,
-                    );
+                    font.render_glyphs(&mut img, text, size,
+                        padding as f32 + (ox * w) as f32,
+                        pen_y + (oy * w) as f32,
+                        outline_color);
                 }
             }
         }
 
         // Draw main text
-        draw_text_mut(
-            &mut img,
-            style.color,
-            padding as i32,
-            padding as i32,
-            scale,
-            &font,
-            text,
-        );
+        font.render_glyphs(&mut img, text, size, padding as f32, pen_y, style.color);
 
         img
     }
@@ -220,17 +562,17 @@ impl AnimatedText {
         (x as i32, y as i32)
     }
 
-    /// Create typewriter reveal progress (0.0 to 1.0 = full text visible)
-    pub fn typewriter_progress(text: &str, progress: f32) -> &str {
-        let char_count = text.chars().count();
-        let visible_chars = (char_count as f32 * progress.clamp(0.0, 1.0)).ceil() as usize;
-        let mut end_byte = 0;
-        for (i, (byte_idx, _)) in text.char_indices().enumerate() {
-            if i >= visible_chars {
-                break;
-            }
-            end_byte = byte_idx + text[byte_idx..].chars().next().unwrap().len_utf8();
+    /// Create typewriter reveal progress (0.0 to 1.0 = full text visible).
+    /// `clusters` are shaped-cluster byte boundaries from
+    /// `TextRenderer::shape_clusters`, so a ligature or combining mark is
+    /// revealed as one whole unit rather than splitting mid-glyph.
+    pub fn typewriter_progress<'a>(text: &'a str, progress: f32, clusters: &[usize]) -> &'a str {
+        if clusters.len() < 2 {
+            return text;
         }
+        let cluster_count = clusters.len() - 1;
+        let visible = (cluster_count as f32 * progress.clamp(0.0, 1.0)).ceil() as usize;
+        let end_byte = clusters[visible.min(cluster_count)];
         &text[..end_byte]
     }
 
@@ -247,16 +589,12 @@ pub struct TitleCard;
 impl TitleCard {
     /// Create a dramatic title card
     pub fn render(title: &str, subtitle: Option<&str>, width: u32, height: u32) -> RgbaImage {
-        let mut img = RgbaImage::new(width, height);
-
         // Dark gradient background
-        for y in 0..height {
-            let t = y as f32 / height as f32;
+        let mut img = crate::shapes::fill(width, height, 0.0, |_x, y, _time| {
+            let t = y / height as f32;
             let gray = (20.0 + 30.0 * t) as u8;
-            for x in 0..width {
-                img.put_pixel(x, y, Rgba([gray, gray / 2, gray / 2, 255]));
-            }
-        }
+            Rgba([gray, gray / 2, gray / 2, 255])
+        });
 
         let text_renderer = TextRenderer::new();
 
@@ -319,6 +657,7 @@ impl LowerThird {
             shadow: false,
             shadow_offset: (0, 0),
             shadow_color: Rgba([0, 0, 0, 0]),
+            font: FontId::Default,
         };
 
         let text_size = (bar_height as f32 * 0.5).min(width as f32 * 0.04);
@@ -0,0 +1,373 @@
+//! Plain-text scene timeline VM
+//!
+//! `script::SceneScript` replaced hardcoded `render_scene_*` functions with a
+//! declarative TOML format, but authoring still means nesting layers inside
+//! mutually-exclusive `[[scene]]` windows. `Timeline` is a second,
+//! lower-ceremony front end for the same idea, inspired by TSC/creditscript
+//! event lists: a plain-text file of `<t_start>-<t_end> COMMAND args...`
+//! lines, one per event, with no enclosing scene at all. Events can overlap
+//! in time -- `SceneManager::render_scripted_timeline` composites every
+//! event whose window contains the current time, in start-time order
+//! (painter's algorithm; events with equal starts keep their declaration
+//! order) -- which a `SceneScript`'s exclusive-window model
+//! can't express.
+//!
+//! Commands (`TEXT`, `CHAR`, `CARD`, `GLOW`, `FADE`, `SPARKLES`) compile down
+//! to a `script::Layer` apiece, reusing its `params`/`x`/`y`/`scale`/`alpha`
+//! fields and `Track::sample` -- so `SceneManager` only has one layer
+//! renderer (`render_scripted_layer`) to maintain, not two. `fade_in`/
+//! `fade_out` compile to an alpha `Track` envelope, `pop_in` compiles to a
+//! sampled `Easing`/`PopIn::get_scale` curve baked into a scale `Track`, and
+//! `pos=`/`at=` compile to named position params or constant coordinate
+//! tracks.
+
+use anyhow::{bail, Context, Result};
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::effects::PopIn;
+use crate::script::{Keyframe, Layer, LayerKind, Track};
+
+/// One parsed line: an absolute `start`..`end` window (in video seconds, not
+/// scene-local) plus the `Layer` it composites.
+#[derive(Debug, Clone)]
+pub struct SceneEvent {
+    pub start: f32,
+    pub end: f32,
+    pub layer: Layer,
+}
+
+/// A flat event list parsed from a plain-text timeline file, sorted by
+/// start time (events with equal starts keep their declaration order).
+#[derive(Debug, Clone, Default)]
+pub struct Timeline {
+    pub events: Vec<SceneEvent>,
+}
+
+impl Timeline {
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .with_context(|| format!("reading scene timeline {}", path.display()))?;
+        Self::parse(&text)
+    }
+
+    fn parse(text: &str) -> Result<Self> {
+        let mut events = Vec::new();
+
+        for (i, raw_line) in text.lines().enumerate() {
+            let line_num = i + 1;
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let event = parse_line(line)
+                .with_context(|| format!("scene timeline line {line_num}: {line:?}"))?;
+            events.push(event);
+        }
+
+        events.sort_by(|a, b| a.start.partial_cmp(&b.start).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(Self { events })
+    }
+}
+
+fn parse_line(line: &str) -> Result<SceneEvent> {
+    let tokens = tokenize(line);
+    let mut iter = tokens.into_iter();
+
+    let window = iter.next().context("missing `<t_start>-<t_end>` window")?;
+    let (start, end) = parse_window(&window)?;
+
+    let command = iter.next().context("missing command")?.to_uppercase();
+    let args: Vec<String> = iter.collect();
+    let layer = build_layer(&command, &args)?;
+
+    Ok(SceneEvent { start, end, layer })
+}
+
+/// Split a line into whitespace-separated tokens, treating `"..."` as one
+/// token so `TEXT "DRAW UNTIL"` keeps its spaces.
+fn tokenize(line: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = line.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if c == '"' {
+            chars.next();
+            let mut token = String::new();
+            for c in chars.by_ref() {
+                if c == '"' {
+                    break;
+                }
+                token.push(c);
+            }
+            tokens.push(token);
+        } else {
+            let mut token = String::new();
+            while let Some(&c) = chars.peek() {
+                if c.is_whitespace() {
+                    break;
+                }
+                token.push(c);
+                chars.next();
+            }
+            tokens.push(token);
+        }
+    }
+
+    tokens
+}
+
+fn parse_window(spec: &str) -> Result<(f32, f32)> {
+    let (start, end) = spec.split_once('-').with_context(|| format!("expected `<start>-<end>`, got {spec:?}"))?;
+    Ok((
+        start.parse().with_context(|| format!("invalid window start {start:?}"))?,
+        end.parse().with_context(|| format!("invalid window end {end:?}"))?,
+    ))
+}
+
+/// Split a command's remaining tokens into bare words (positional content,
+/// or the `pop_in` modifier flag) and `key=value` pairs.
+fn split_args(args: &[String]) -> (Vec<String>, HashMap<String, String>) {
+    let mut bare = Vec::new();
+    let mut kv = HashMap::new();
+
+    for arg in args {
+        match arg.split_once('=') {
+            Some((key, value)) => {
+                kv.insert(key.to_string(), value.to_string());
+            }
+            None => bare.push(arg.clone()),
+        }
+    }
+
+    (bare, kv)
+}
+
+fn has_pop_in(bare: &[String]) -> bool {
+    bare.iter().any(|b| b == "pop_in")
+}
+
+/// The one non-`pop_in` bare token a command expects, if any (`FADE`'s
+/// direction, `TEXT`'s quoted content).
+fn positional(bare: &[String]) -> Option<&str> {
+    bare.iter().find(|b| b.as_str() != "pop_in").map(String::as_str)
+}
+
+fn constant_track(value: f32) -> Track {
+    Track { keyframes: vec![Keyframe { t: 0.0, value }] }
+}
+
+/// Bake `PopIn::get_scale`'s overshoot curve into a `Track` so the existing
+/// linear-interpolating `Track::sample` reproduces it closely enough for a
+/// handful of video frames, without teaching `render_scripted_layer` a
+/// second scale curve.
+fn pop_in_scale_track(base_scale: f32) -> Track {
+    const SAMPLES: usize = 12;
+    let keyframes = (0..=SAMPLES)
+        .map(|i| {
+            let t = i as f32 / SAMPLES as f32;
+            Keyframe { t, value: PopIn::get_scale(t, 0.8) * base_scale }
+        })
+        .collect();
+    Track { keyframes }
+}
+
+/// Compile `fade_in=`/`fade_out=` into an alpha envelope `Track`: 0 -> 1 over
+/// `fade_in`, steady at 1, then 1 -> 0 over the final `fade_out`. Absent
+/// both, the default (steady 1.0) `Track::sample` already falls back to is
+/// fine, so an empty track is returned.
+fn fade_alpha_track(kv: &HashMap<String, String>) -> Result<Track> {
+    let fade_in: f32 = match kv.get("fade_in") {
+        Some(v) => v.parse().with_context(|| format!("fade_in must be a number, got {v:?}"))?,
+        None => 0.0,
+    };
+    let fade_out: f32 = match kv.get("fade_out") {
+        Some(v) => v.parse().with_context(|| format!("fade_out must be a number, got {v:?}"))?,
+        None => 0.0,
+    };
+
+    if fade_in <= 0.0 && fade_out <= 0.0 {
+        return Ok(Track::default());
+    }
+
+    let mut keyframes = vec![Keyframe { t: 0.0, value: if fade_in > 0.0 { 0.0 } else { 1.0 } }];
+    if fade_in > 0.0 {
+        keyframes.push(Keyframe { t: fade_in.min(1.0), value: 1.0 });
+    }
+    if fade_out > 0.0 {
+        keyframes.push(Keyframe { t: (1.0 - fade_out).max(0.0), value: 1.0 });
+        keyframes.push(Keyframe { t: 1.0, value: 0.0 });
+    }
+
+    Ok(Track { keyframes })
+}
+
+/// Stash a `pos=<h>,<v>` spec as `pos_x`/`pos_y` params for
+/// `SceneManager::layer_position` to resolve once it knows the rendered
+/// content's size. `pos=corner` is shorthand for the bottom-right corner.
+fn apply_pos_spec(params: &mut HashMap<String, String>, spec: &str) {
+    if spec == "corner" {
+        params.insert("pos_x".to_string(), "right".to_string());
+        params.insert("pos_y".to_string(), "bottom".to_string());
+        return;
+    }
+
+    match spec.split_once(',') {
+        Some((h, v)) => {
+            params.insert("pos_x".to_string(), h.trim().to_string());
+            params.insert("pos_y".to_string(), v.trim().to_string());
+        }
+        None => {
+            params.insert("pos_x".to_string(), spec.trim().to_string());
+        }
+    }
+}
+
+fn parse_at(spec: &str) -> Result<(f32, f32)> {
+    let (x, y) = spec.split_once(',').with_context(|| format!("`at` expects `x,y`, got {spec:?}"))?;
+    Ok((
+        x.trim().parse().with_context(|| format!("invalid x in `at={spec}`"))?,
+        y.trim().parse().with_context(|| format!("invalid y in `at={spec}`"))?,
+    ))
+}
+
+fn build_layer(command: &str, args: &[String]) -> Result<Layer> {
+    let (bare, mut kv) = split_args(args);
+    let ease = kv.remove("ease");
+
+    let mut layer = match command {
+        "TEXT" => build_text(&bare, kv)?,
+        "CHAR" => build_char(&bare, kv)?,
+        "CARD" => build_card(&bare, kv)?,
+        "GLOW" => build_glow(kv)?,
+        "FADE" => build_fade(&bare),
+        "SPARKLES" => build_sparkles(kv)?,
+        other => bail!("unknown command `{other}`"),
+    };
+
+    if let Some(ease) = ease {
+        layer.params.insert("ease".to_string(), ease);
+    }
+
+    Ok(layer)
+}
+
+fn build_text(bare: &[String], mut kv: HashMap<String, String>) -> Result<Layer> {
+    let content = positional(bare).context("TEXT requires a quoted string, e.g. TEXT \"hello\"")?;
+
+    let mut params = HashMap::new();
+    params.insert("content".to_string(), content.to_string());
+    if let Some(style) = kv.remove("style") {
+        params.insert("style".to_string(), style);
+    }
+    if let Some(size) = kv.remove("size") {
+        params.insert("size".to_string(), size);
+    }
+    if let Some(pos) = kv.remove("pos") {
+        apply_pos_spec(&mut params, &pos);
+    }
+
+    let scale = if has_pop_in(bare) { pop_in_scale_track(1.0) } else { Track::default() };
+    let alpha = fade_alpha_track(&kv)?;
+
+    Ok(Layer { kind: LayerKind::Text, params, x: Track::default(), y: Track::default(), scale, alpha })
+}
+
+fn build_char(bare: &[String], mut kv: HashMap<String, String>) -> Result<Layer> {
+    let mut params = HashMap::new();
+    if let Some(expr) = kv.remove("expr") {
+        params.insert("expression".to_string(), expr.to_lowercase());
+    }
+    if let Some(pos) = kv.remove("pos") {
+        apply_pos_spec(&mut params, &pos);
+    }
+
+    let base_scale: f32 = match kv.remove("scale") {
+        Some(v) => v.parse().with_context(|| format!("scale must be a number, got {v:?}"))?,
+        None => 1.0,
+    };
+    let scale = if has_pop_in(bare) { pop_in_scale_track(base_scale) } else { constant_track(base_scale) };
+    let alpha = fade_alpha_track(&kv)?;
+
+    Ok(Layer { kind: LayerKind::Character, params, x: Track::default(), y: Track::default(), scale, alpha })
+}
+
+fn build_card(bare: &[String], mut kv: HashMap<String, String>) -> Result<Layer> {
+    let mut params = HashMap::new();
+    if let Some(color) = kv.remove("color") {
+        params.insert("color".to_string(), color.to_lowercase());
+    }
+    if let Some(num) = kv.remove("num") {
+        params.insert("number".to_string(), num);
+    }
+    if let Some(card) = kv.remove("card") {
+        params.insert("card".to_string(), card.to_lowercase());
+    }
+
+    let (mut x, mut y) = (Track::default(), Track::default());
+    if let Some(at) = kv.remove("at") {
+        let (px, py) = parse_at(&at)?;
+        x = constant_track(px);
+        y = constant_track(py);
+    }
+
+    let scale = if has_pop_in(bare) { pop_in_scale_track(1.0) } else { Track::default() };
+    let alpha = fade_alpha_track(&kv)?;
+
+    Ok(Layer { kind: LayerKind::Card, params, x, y, scale, alpha })
+}
+
+fn build_glow(mut kv: HashMap<String, String>) -> Result<Layer> {
+    let mut params = HashMap::new();
+    if let Some(color) = kv.remove("color") {
+        params.insert("color".to_string(), color);
+    }
+    if let Some(radius) = kv.remove("radius") {
+        params.insert("radius".to_string(), radius);
+    }
+    if let Some(intensity) = kv.remove("intensity") {
+        params.insert("intensity".to_string(), intensity);
+    }
+
+    let (mut x, mut y) = (Track::default(), Track::default());
+    if let Some(at) = kv.remove("at") {
+        let (px, py) = parse_at(&at)?;
+        x = constant_track(px);
+        y = constant_track(py);
+    }
+
+    let alpha = fade_alpha_track(&kv)?;
+
+    Ok(Layer { kind: LayerKind::Glow, params, x, y, scale: Track::default(), alpha })
+}
+
+fn build_fade(bare: &[String]) -> Layer {
+    let direction = positional(bare).unwrap_or("black");
+    let mut params = HashMap::new();
+    params.insert(
+        "direction".to_string(),
+        if direction == "black" { "to_black".to_string() } else { "from_black".to_string() },
+    );
+
+    Layer { kind: LayerKind::Fade, params, x: Track::default(), y: Track::default(), scale: Track::default(), alpha: Track::default() }
+}
+
+fn build_sparkles(mut kv: HashMap<String, String>) -> Result<Layer> {
+    let mut params = HashMap::new();
+    if let Some(count) = kv.remove("count") {
+        params.insert("count".to_string(), count);
+    }
+    if let Some(seed) = kv.remove("seed") {
+        params.insert("seed".to_string(), seed);
+    }
+
+    let alpha = fade_alpha_track(&kv)?;
+
+    Ok(Layer { kind: LayerKind::Particles, params, x: Track::default(), y: Track::default(), scale: Track::default(), alpha })
+}
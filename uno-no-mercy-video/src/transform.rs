@@ -0,0 +1,215 @@
+//! 2D affine transforms for compositing
+//!
+//! `CardRenderer::render_fan` used to admit it only offset cards sideways
+//! instead of rotating them ("rotation would need more complex
+//! implementation"), and `render_flying_cards` had no tilt at all. `Affine2`
+//! stores a 2x3 matrix (rotation + translation + optional scale), and
+//! `composite_image_transformed` maps each destination pixel back through
+//! its inverse to bilinearly sample the source -- the matrix-and-inverse-map
+//! approach Vello's scene graph uses to composite transformed layers.
+
+use image::{Rgba, RgbaImage};
+
+use crate::blend::BlendMode;
+
+/// A 2x3 affine matrix mapping source-image space to destination space:
+/// `x' = a*x + b*y + tx`, `y' = c*x + d*y + ty`.
+#[derive(Debug, Clone, Copy)]
+pub struct Affine2 {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+    pub tx: f32,
+    pub ty: f32,
+}
+
+impl Affine2 {
+    pub fn identity() -> Self {
+        Self { a: 1.0, b: 0.0, c: 0.0, d: 1.0, tx: 0.0, ty: 0.0 }
+    }
+
+    /// A pure translation by `(tx, ty)`, no rotation or scale.
+    pub fn translation(tx: f32, ty: f32) -> Self {
+        Self { tx, ty, ..Self::identity() }
+    }
+
+    /// Rotate by `angle` radians around the origin, then scale by `scale`.
+    pub fn rotation_scale(angle: f32, scale: f32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self { a: cos * scale, b: -sin * scale, c: sin * scale, d: cos * scale, tx: 0.0, ty: 0.0 }
+    }
+
+    /// Scale independently per axis around the origin, with no rotation --
+    /// e.g. `CardAnimator::flip`'s horizontal squeeze through zero.
+    pub fn scale_xy(scale_x: f32, scale_y: f32) -> Self {
+        Self { a: scale_x, b: 0.0, c: 0.0, d: scale_y, tx: 0.0, ty: 0.0 }
+    }
+
+    /// Apply `m` around `pivot` in source-local space, then place that pivot
+    /// at `dest_pivot` in destination space. This is how `render_fan` turns
+    /// each card around its own bottom-center point.
+    pub fn around_pivot(m: Affine2, pivot: (f32, f32), dest_pivot: (f32, f32)) -> Self {
+        let (mapped_x, mapped_y) = m.apply(pivot.0, pivot.1);
+        Self { tx: dest_pivot.0 - mapped_x, ty: dest_pivot.1 - mapped_y, ..m }
+    }
+
+    fn apply(&self, x: f32, y: f32) -> (f32, f32) {
+        (self.a * x + self.b * y + self.tx, self.c * x + self.d * y + self.ty)
+    }
+
+    /// Invert the matrix, for mapping destination coordinates back to
+    /// source space during sampling.
+    fn inverse(&self) -> Option<Affine2> {
+        let det = self.a * self.d - self.b * self.c;
+        if det.abs() < 1e-6 {
+            return None;
+        }
+
+        let inv_det = 1.0 / det;
+        let a = self.d * inv_det;
+        let b = -self.b * inv_det;
+        let c = -self.c * inv_det;
+        let d = self.a * inv_det;
+        let tx = -(a * self.tx + b * self.ty);
+        let ty = -(c * self.tx + d * self.ty);
+
+        Some(Affine2 { a, b, c, d, tx, ty })
+    }
+}
+
+/// Bilinearly sample `src` at fractional pixel-grid coordinates `(x, y)`,
+/// skipping any of the four neighbors that fall outside `src` and
+/// premultiplying by alpha so partially-transparent neighbors don't bleed
+/// their color into the result. Returns `None` if every neighbor was either
+/// out of bounds or fully transparent. `pub(crate)` so other per-pixel
+/// resampling effects (`effects::Wiggle`) don't need their own copy.
+pub(crate) fn sample_bilinear(src: &RgbaImage, x: f32, y: f32) -> Option<Rgba<u8>> {
+    let x0 = x.floor();
+    let y0 = y.floor();
+    let fx = x - x0;
+    let fy = y - y0;
+
+    let corners = [
+        (x0, y0, (1.0 - fx) * (1.0 - fy)),
+        (x0 + 1.0, y0, fx * (1.0 - fy)),
+        (x0, y0 + 1.0, (1.0 - fx) * fy),
+        (x0 + 1.0, y0 + 1.0, fx * fy),
+    ];
+
+    let mut premul = [0.0f32; 3];
+    let mut alpha_sum = 0.0f32;
+    let mut weight_total = 0.0f32;
+
+    for (cx, cy, weight) in corners {
+        if weight <= 0.0 || cx < 0.0 || cy < 0.0 || cx >= src.width() as f32 || cy >= src.height() as f32 {
+            continue;
+        }
+
+        let pixel = src.get_pixel(cx as u32, cy as u32);
+        let a = pixel[3] as f32 / 255.0;
+        premul[0] += pixel[0] as f32 * a * weight;
+        premul[1] += pixel[1] as f32 * a * weight;
+        premul[2] += pixel[2] as f32 * a * weight;
+        alpha_sum += a * weight;
+        weight_total += weight;
+    }
+
+    if weight_total <= 0.0 || alpha_sum <= 0.0 {
+        return None;
+    }
+
+    let out_a = alpha_sum / weight_total;
+    Some(Rgba([
+        (premul[0] / alpha_sum).clamp(0.0, 255.0) as u8,
+        (premul[1] / alpha_sum).clamp(0.0, 255.0) as u8,
+        (premul[2] / alpha_sum).clamp(0.0, 255.0) as u8,
+        (out_a * 255.0).clamp(0.0, 255.0) as u8,
+    ]))
+}
+
+/// Composite `src` into `dest` through `transform` (source-space to
+/// destination-space), blended with `mode`. Walks the rotated bounding box
+/// of `src` in destination space, mapping each destination pixel back
+/// through the inverse transform to bilinearly sample `src`.
+pub fn composite_image_transformed(dest: &mut RgbaImage, src: &RgbaImage, transform: Affine2, mode: BlendMode) {
+    let Some(inverse) = transform.inverse() else { return };
+
+    let corners = [
+        transform.apply(0.0, 0.0),
+        transform.apply(src.width() as f32, 0.0),
+        transform.apply(0.0, src.height() as f32),
+        transform.apply(src.width() as f32, src.height() as f32),
+    ];
+
+    let min_x = corners.iter().map(|c| c.0).fold(f32::INFINITY, f32::min).floor().max(0.0) as i32;
+    let max_x = corners.iter().map(|c| c.0).fold(f32::NEG_INFINITY, f32::max).ceil().min(dest.width() as f32) as i32;
+    let min_y = corners.iter().map(|c| c.1).fold(f32::INFINITY, f32::min).floor().max(0.0) as i32;
+    let max_y = corners.iter().map(|c| c.1).fold(f32::NEG_INFINITY, f32::max).ceil().min(dest.height() as f32) as i32;
+
+    for dy in min_y..max_y {
+        for dx in min_x..max_x {
+            let (sx, sy) = inverse.apply(dx as f32 + 0.5, dy as f32 + 0.5);
+            let Some(source) = sample_bilinear(src, sx - 0.5, sy - 0.5) else { continue };
+            if source[3] == 0 {
+                continue;
+            }
+
+            let dest_pixel = dest.get_pixel(dx as u32, dy as u32);
+            let blended = mode.blend_pixels(source, *dest_pixel);
+            dest.put_pixel(dx as u32, dy as u32, blended);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(got: (f32, f32), want: (f32, f32)) {
+        assert!(
+            (got.0 - want.0).abs() < 1e-4 && (got.1 - want.1).abs() < 1e-4,
+            "got {got:?}, want {want:?}"
+        );
+    }
+
+    #[test]
+    fn identity_leaves_points_unchanged() {
+        assert_close(Affine2::identity().apply(3.0, -4.0), (3.0, -4.0));
+    }
+
+    #[test]
+    fn translation_offsets_points() {
+        assert_close(Affine2::translation(5.0, -2.0).apply(1.0, 1.0), (6.0, -1.0));
+    }
+
+    #[test]
+    fn rotation_scale_quarter_turn_swaps_axes() {
+        // A 90-degree rotation should send (1, 0) to (0, 1).
+        let rotated = Affine2::rotation_scale(std::f32::consts::FRAC_PI_2, 1.0).apply(1.0, 0.0);
+        assert_close(rotated, (0.0, 1.0));
+    }
+
+    #[test]
+    fn around_pivot_keeps_the_pivot_fixed_at_its_destination() {
+        let m = Affine2::rotation_scale(std::f32::consts::FRAC_PI_2, 1.0);
+        let transform = Affine2::around_pivot(m, (10.0, 10.0), (50.0, 60.0));
+        assert_close(transform.apply(10.0, 10.0), (50.0, 60.0));
+    }
+
+    #[test]
+    fn inverse_of_inverse_recovers_the_original_point() {
+        let transform = Affine2::around_pivot(Affine2::rotation_scale(0.4, 1.3), (2.0, 3.0), (20.0, -5.0));
+        let inverse = transform.inverse().expect("invertible transform");
+
+        let point = (7.0, 11.0);
+        let mapped = transform.apply(point.0, point.1);
+        let back = inverse.apply(mapped.0, mapped.1);
+        assert_close(back, point);
+    }
+
+    #[test]
+    fn degenerate_scale_has_no_inverse() {
+        assert!(Affine2::scale_xy(0.0, 1.0).inverse().is_none());
+    }
+}
@@ -0,0 +1,248 @@
+//! General image-to-image transitions
+//!
+//! `Fade` only ever fades to/from solid black. `Transition` blends between
+//! two arbitrary frames over `progress`, with a choice of `TransitionStyle`:
+//! `Crossfade` (straight alpha-over of `b` at opacity `progress`),
+//! `Dissolve` (a stable per-pixel pseudo-random threshold so pixels flip
+//! from `a` to `b` individually as `progress` rises), a directional `Wipe`
+//! (a moving hard- or soft-edged boundary revealing `b`), or `Iris` (a
+//! circle centered on the frame, expanding from nothing at `progress = 0`
+//! to covering the farthest corner at `progress = 1`, with an optional
+//! feathered edge). All four accept an `Easing`-style `fn(f32) -> f32` to
+//! reshape `progress` first, matching how `Zoom`/`Slide` already take their
+//! easing.
+
+use image::{Rgba, RgbaImage};
+
+use crate::blend::BlendMode;
+
+/// Which direction a `Wipe` reveals `b` from.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WipeDirection {
+    LeftToRight,
+    RightToLeft,
+    TopToBottom,
+    BottomToTop,
+}
+
+/// How `Transition::apply` blends `a` into `b`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TransitionStyle {
+    Crossfade,
+    Dissolve,
+    Wipe { direction: WipeDirection, softness: f32 },
+    Iris { softness: f32 },
+}
+
+pub struct Transition;
+
+impl Transition {
+    /// Blend `a` into `b` over `progress` (`0.0` = all `a`, `1.0` = all
+    /// `b`), reshaped first by `easing`. `a` and `b` must be the same size.
+    pub fn apply(a: &RgbaImage, b: &RgbaImage, progress: f32, style: TransitionStyle, easing: fn(f32) -> f32) -> RgbaImage {
+        let f = easing(progress.clamp(0.0, 1.0));
+        match style {
+            TransitionStyle::Crossfade => Self::crossfade(a, b, f),
+            TransitionStyle::Dissolve => Self::dissolve(a, b, f),
+            TransitionStyle::Wipe { direction, softness } => Self::wipe(a, b, f, direction, softness),
+            TransitionStyle::Iris { softness } => Self::iris(a, b, f, softness),
+        }
+    }
+
+    fn crossfade(a: &RgbaImage, b: &RgbaImage, f: f32) -> RgbaImage {
+        let mut result = a.clone();
+        let opacity = f.clamp(0.0, 1.0);
+
+        for (x, y, src) in b.enumerate_pixels() {
+            let faded = Rgba([src[0], src[1], src[2], (src[3] as f32 * opacity) as u8]);
+            let dest = result.get_pixel(x, y);
+            let blended = BlendMode::SrcOver.blend_pixels(faded, *dest);
+            result.put_pixel(x, y, blended);
+        }
+        result
+    }
+
+    /// Stable per-pixel pseudo-random threshold map -- each pixel flips
+    /// from `a` to `b` the instant `f` passes its own threshold, instead of
+    /// every pixel fading uniformly.
+    fn dissolve(a: &RgbaImage, b: &RgbaImage, f: f32) -> RgbaImage {
+        let mut result = RgbaImage::new(a.width(), a.height());
+
+        for (x, y, pa) in a.enumerate_pixels() {
+            let pb = b.get_pixel(x, y);
+            let threshold = Self::dissolve_threshold(x, y);
+            result.put_pixel(x, y, if f >= threshold { *pb } else { *pa });
+        }
+        result
+    }
+
+    /// A cheap, stable per-pixel hash in `[0, 1)` so the same pixel always
+    /// dissolves at the same `f` across frames.
+    fn dissolve_threshold(x: u32, y: u32) -> f32 {
+        let h = x.wrapping_mul(374761393).wrapping_add(y.wrapping_mul(668265263));
+        let h = (h ^ (h >> 13)).wrapping_mul(1274126177);
+        let h = h ^ (h >> 16);
+        h as f32 / u32::MAX as f32
+    }
+
+    /// A moving boundary line along `direction`, blended over `softness` of
+    /// normalized distance so the edge can be hard (`softness` near 0) or
+    /// feathered.
+    fn wipe(a: &RgbaImage, b: &RgbaImage, f: f32, direction: WipeDirection, softness: f32) -> RgbaImage {
+        let width = a.width();
+        let height = a.height();
+        let mut result = RgbaImage::new(width, height);
+        let softness = softness.max(0.0001);
+
+        for (x, y, pa) in a.enumerate_pixels() {
+            let pb = b.get_pixel(x, y);
+            let pos = match direction {
+                WipeDirection::LeftToRight => x as f32 / width.max(1) as f32,
+                WipeDirection::RightToLeft => 1.0 - x as f32 / width.max(1) as f32,
+                WipeDirection::TopToBottom => y as f32 / height.max(1) as f32,
+                WipeDirection::BottomToTop => 1.0 - y as f32 / height.max(1) as f32,
+            };
+
+            // The boundary sweeps from just before 0 to just past 1 across
+            // f in [0, 1], so the soft edge fully enters and leaves frame.
+            let edge = f * (1.0 + softness) - softness * 0.5;
+            let reveal = ((edge - pos) / softness + 0.5).clamp(0.0, 1.0);
+
+            result.put_pixel(x, y, Self::mix(*pa, *pb, reveal));
+        }
+        result
+    }
+
+    /// A circular mask centered on the frame, growing from `a` outward
+    /// until it reveals `b` everywhere. `radius = f * max_dim` where
+    /// `max_dim` is the farthest corner's distance from center, so at `f = 1`
+    /// the edge reaches that corner. `softness` feathers the circle's edge the
+    /// same way `wipe`'s `softness` feathers its boundary line; the feather is
+    /// centered on the edge, so at `f = 1` pixels within half the feather of
+    /// the farthest corner are still partly `a`.
+    fn iris(a: &RgbaImage, b: &RgbaImage, f: f32, softness: f32) -> RgbaImage {
+        let width = a.width();
+        let height = a.height();
+        let mut result = RgbaImage::new(width, height);
+        let softness = softness.max(0.0001);
+
+        let cx = width as f32 / 2.0;
+        let cy = height as f32 / 2.0;
+        let max_dim = (cx * cx + cy * cy).sqrt().max(1.0);
+        let radius = f * max_dim;
+
+        for (x, y, pa) in a.enumerate_pixels() {
+            let pb = b.get_pixel(x, y);
+            let dx = x as f32 - cx;
+            let dy = y as f32 - cy;
+            let dist = (dx * dx + dy * dy).sqrt() / max_dim;
+
+            let edge = radius / max_dim;
+            let reveal = ((edge - dist) / softness + 0.5).clamp(0.0, 1.0);
+
+            result.put_pixel(x, y, Self::mix(*pa, *pb, reveal));
+        }
+        result
+    }
+
+    fn mix(a: Rgba<u8>, b: Rgba<u8>, t: f32) -> Rgba<u8> {
+        Rgba([
+            (a[0] as f32 + (b[0] as f32 - a[0] as f32) * t) as u8,
+            (a[1] as f32 + (b[1] as f32 - a[1] as f32) * t) as u8,
+            (a[2] as f32 + (b[2] as f32 - a[2] as f32) * t) as u8,
+            (a[3] as f32 + (b[3] as f32 - a[3] as f32) * t) as u8,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::effects::Easing;
+
+    fn solid(color: Rgba<u8>) -> RgbaImage {
+        RgbaImage::from_pixel(8, 8, color)
+    }
+
+    const BLACK: Rgba<u8> = Rgba([0, 0, 0, 255]);
+    const WHITE: Rgba<u8> = Rgba([255, 255, 255, 255]);
+
+    #[test]
+    fn crossfade_is_all_a_at_zero_and_all_b_at_one() {
+        let a = solid(BLACK);
+        let b = solid(WHITE);
+
+        let at_zero = Transition::apply(&a, &b, 0.0, TransitionStyle::Crossfade, Easing::linear);
+        let at_one = Transition::apply(&a, &b, 1.0, TransitionStyle::Crossfade, Easing::linear);
+
+        assert_eq!(*at_zero.get_pixel(0, 0), BLACK);
+        assert_eq!(*at_one.get_pixel(0, 0), WHITE);
+    }
+
+    #[test]
+    fn dissolve_is_all_a_at_zero_and_all_b_at_one() {
+        let a = solid(BLACK);
+        let b = solid(WHITE);
+
+        let at_zero = Transition::apply(&a, &b, 0.0, TransitionStyle::Dissolve, Easing::linear);
+        let at_one = Transition::apply(&a, &b, 1.0, TransitionStyle::Dissolve, Easing::linear);
+
+        assert_eq!(*at_zero.get_pixel(3, 5), BLACK);
+        assert_eq!(*at_one.get_pixel(3, 5), WHITE);
+    }
+
+    #[test]
+    fn dissolve_threshold_is_stable_for_the_same_pixel() {
+        assert_eq!(Transition::dissolve_threshold(12, 34), Transition::dissolve_threshold(12, 34));
+    }
+
+    #[test]
+    fn dissolve_threshold_is_in_zero_one_range() {
+        for (x, y) in [(0, 0), (1, 0), (0, 1), (999, 12), (12, 999)] {
+            let threshold = Transition::dissolve_threshold(x, y);
+            assert!((0.0..1.0).contains(&threshold), "threshold {threshold} out of range for ({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn wipe_reveals_b_past_the_boundary_and_keeps_a_before_it() {
+        let a = solid(BLACK);
+        let b = solid(WHITE);
+        let style = TransitionStyle::Wipe { direction: WipeDirection::LeftToRight, softness: 0.01 };
+
+        let result = Transition::apply(&a, &b, 0.5, style, Easing::linear);
+        // `LeftToRight` reveals `b` starting from the left, so at the
+        // halfway point the left edge has already flipped and the right
+        // edge hasn't caught up yet.
+        assert_eq!(*result.get_pixel(0, 0), WHITE);
+        assert_eq!(*result.get_pixel(7, 0), BLACK);
+    }
+
+    #[test]
+    fn iris_is_all_a_at_zero_and_all_b_at_one() {
+        let a = solid(BLACK);
+        let b = solid(WHITE);
+        let style = TransitionStyle::Iris { softness: 0.08 };
+
+        let at_zero = Transition::apply(&a, &b, 0.0, style, Easing::linear);
+        let at_one = Transition::apply(&a, &b, 1.0, style, Easing::linear);
+
+        // The corner is the circle's farthest point, so it's still
+        // unrevealed right as the circle starts growing at f = 0; the
+        // center is always well inside it, so it's fully revealed by f = 1.
+        assert_eq!(*at_zero.get_pixel(0, 0), BLACK);
+        assert_eq!(*at_one.get_pixel(4, 4), WHITE);
+    }
+
+    #[test]
+    fn apply_clamps_progress_outside_zero_one() {
+        let a = solid(BLACK);
+        let b = solid(WHITE);
+
+        let below = Transition::apply(&a, &b, -1.0, TransitionStyle::Crossfade, Easing::linear);
+        let above = Transition::apply(&a, &b, 2.0, TransitionStyle::Crossfade, Easing::linear);
+
+        assert_eq!(*below.get_pixel(0, 0), BLACK);
+        assert_eq!(*above.get_pixel(0, 0), WHITE);
+    }
+}
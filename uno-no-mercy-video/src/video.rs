@@ -3,10 +3,59 @@
 //! Handles frame composition, backgrounds, and visual effects
 //! with professional quality rendering.
 
-use image::{Rgba, RgbaImage};
+use image::{GrayImage, Rgba, RgbaImage};
 use imageproc::drawing::draw_filled_rect_mut;
 use imageproc::rect::Rect;
 
+use crate::blend::BlendMode;
+use crate::brush::Brush;
+
+/// A per-channel multiply/add color curve -- `out = clamp(in * mult + add,
+/// 0, 255)`, the same add/multiply model vector-graphics engines use for
+/// tinting and brightness. `composite_with_transform` applies this to a
+/// layer's RGBA channels before the usual source-over combine, so a
+/// character, card, or text layer can be tinted or pulsed without a
+/// bespoke full-frame `Flash`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorTransform {
+    pub mult: [f32; 4],
+    pub add: [i32; 4],
+}
+
+impl ColorTransform {
+    /// No-op transform: `composite_with_transform` takes a fast path and
+    /// skips the per-pixel math entirely when given this.
+    pub const IDENTITY: ColorTransform = ColorTransform { mult: [1.0, 1.0, 1.0, 1.0], add: [0, 0, 0, 0] };
+
+    fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    fn apply(&self, pixel: Rgba<u8>) -> Rgba<u8> {
+        let channel = |c: usize| -> u8 {
+            (pixel[c] as f32 * self.mult[c] + self.add[c] as f32).clamp(0.0, 255.0) as u8
+        };
+        Rgba([channel(0), channel(1), channel(2), channel(3)])
+    }
+
+    /// Tint towards `rgba` by `strength` (`0.0` = unchanged, `1.0` = fully
+    /// `rgba`), leaving alpha untouched.
+    pub fn tint(rgba: Rgba<u8>, strength: f32) -> Self {
+        let s = strength.clamp(0.0, 1.0);
+        let mult = 1.0 - s;
+        Self {
+            mult: [mult, mult, mult, 1.0],
+            add: [(rgba[0] as f32 * s) as i32, (rgba[1] as f32 * s) as i32, (rgba[2] as f32 * s) as i32, 0],
+        }
+    }
+
+    /// Scale brightness by `factor` (`1.0` = unchanged), leaving alpha
+    /// untouched.
+    pub fn brightness(factor: f32) -> Self {
+        Self { mult: [factor, factor, factor, 1.0], add: [0, 0, 0, 0] }
+    }
+}
+
 /// Frame composer for layering multiple elements
 pub struct FrameComposer {
     width: u32,
@@ -74,6 +123,139 @@ impl FrameComposer {
         }
     }
 
+    /// Composite `layer` onto `frame` at `(x, y)`, blending per-channel
+    /// with `mode` (`blend::BlendMode` -- the separable modes plus `Add`)
+    /// before the usual source-over alpha combine, instead of
+    /// `composite_with_alpha`'s fixed plain source-over. This is what lets
+    /// the light-beam/energy-burst backgrounds pick a real blend mode
+    /// instead of hardcoding their own additive math, and lets cards layer
+    /// over backgrounds with a proper glow.
+    pub fn composite_with_mode(&self, frame: &mut RgbaImage, layer: &RgbaImage, x: i32, y: i32, alpha: f32, mode: BlendMode) {
+        for (lx, ly, pixel) in layer.enumerate_pixels() {
+            let fx = x + lx as i32;
+            let fy = y + ly as i32;
+
+            if fx < 0 || fy < 0 || fx as u32 >= frame.width() || fy as u32 >= frame.height() {
+                continue;
+            }
+
+            let src = Rgba([pixel[0], pixel[1], pixel[2], (pixel[3] as f32 * alpha) as u8]);
+            if src[3] == 0 {
+                continue;
+            }
+
+            let dest = frame.get_pixel(fx as u32, fy as u32);
+            let blended = mode.blend_pixels(src, *dest);
+            frame.put_pixel(fx as u32, fy as u32, blended);
+        }
+    }
+
+    /// Composite `layer` onto `frame` with `transform` applied to each
+    /// source pixel first (e.g. flashing a character red on `MindBlown`,
+    /// or desaturating everything but a spotlit card). `ColorTransform::
+    /// IDENTITY` takes a fast path straight to the plain `composite_with_alpha`.
+    pub fn composite_with_transform(&self, frame: &mut RgbaImage, layer: &RgbaImage, x: i32, y: i32, alpha: f32, transform: &ColorTransform) {
+        if transform.is_identity() {
+            self.composite_with_alpha(frame, layer, x, y, alpha);
+            return;
+        }
+
+        for (lx, ly, pixel) in layer.enumerate_pixels() {
+            let fx = x + lx as i32;
+            let fy = y + ly as i32;
+
+            if fx < 0 || fy < 0 || fx as u32 >= frame.width() || fy as u32 >= frame.height() {
+                continue;
+            }
+
+            let transformed = transform.apply(*pixel);
+            let src = Rgba([transformed[0], transformed[1], transformed[2], (transformed[3] as f32 * alpha) as u8]);
+            if src[3] == 0 {
+                continue;
+            }
+
+            let dest = frame.get_pixel(fx as u32, fy as u32);
+            let blended = BlendMode::SrcOver.blend_pixels(src, *dest);
+            frame.put_pixel(fx as u32, fy as u32, blended);
+        }
+    }
+
+    /// Composite `layer` onto `frame`, multiplying each source pixel's
+    /// alpha by the corresponding `mask` value (`0` = fully hidden, `255` =
+    /// fully shown), so callers can reveal a layer through an arbitrary
+    /// shape instead of always writing its full rectangle -- the
+    /// compositing-side complement to `Backgrounds::epic_reveal`'s radial
+    /// wipe. `mask` must be the same size as `layer`.
+    pub fn composite_masked(&self, frame: &mut RgbaImage, layer: &RgbaImage, x: i32, y: i32, mask: &GrayImage) {
+        for (lx, ly, pixel) in layer.enumerate_pixels() {
+            let fx = x + lx as i32;
+            let fy = y + ly as i32;
+
+            if fx < 0 || fy < 0 || fx as u32 >= frame.width() || fy as u32 >= frame.height() {
+                continue;
+            }
+
+            let mask_value = mask.get_pixel(lx, ly)[0] as f32 / 255.0;
+            let src_alpha = (pixel[3] as f32 / 255.0) * mask_value;
+            if src_alpha <= 0.0 {
+                continue;
+            }
+
+            let dest_pixel = frame.get_pixel(fx as u32, fy as u32);
+            let dest_alpha = dest_pixel[3] as f32 / 255.0;
+
+            let out_alpha = src_alpha + dest_alpha * (1.0 - src_alpha);
+            if out_alpha <= 0.0 {
+                continue;
+            }
+
+            let r = (pixel[0] as f32 * src_alpha + dest_pixel[0] as f32 * dest_alpha * (1.0 - src_alpha)) / out_alpha;
+            let g = (pixel[1] as f32 * src_alpha + dest_pixel[1] as f32 * dest_alpha * (1.0 - src_alpha)) / out_alpha;
+            let b = (pixel[2] as f32 * src_alpha + dest_pixel[2] as f32 * dest_alpha * (1.0 - src_alpha)) / out_alpha;
+
+            frame.put_pixel(fx as u32, fy as u32, Rgba([r as u8, g as u8, b as u8, (out_alpha * 255.0) as u8]));
+        }
+    }
+
+    /// Composite `layer` onto `frame`, skipping any destination pixel
+    /// outside `clip` (clamped to the frame bounds), so callers can build
+    /// vignette reveals or spotlight-shaped cutouts without writing the
+    /// layer's full rectangle.
+    pub fn composite_clipped(&self, frame: &mut RgbaImage, layer: &RgbaImage, x: i32, y: i32, clip: Rect) {
+        let clip_left = clip.left().max(0);
+        let clip_top = clip.top().max(0);
+        let clip_right = (clip.left() + clip.width() as i32).min(self.width as i32);
+        let clip_bottom = (clip.top() + clip.height() as i32).min(self.height as i32);
+
+        for (lx, ly, pixel) in layer.enumerate_pixels() {
+            let fx = x + lx as i32;
+            let fy = y + ly as i32;
+
+            if fx < clip_left || fy < clip_top || fx >= clip_right || fy >= clip_bottom {
+                continue;
+            }
+
+            let src_alpha = pixel[3] as f32 / 255.0;
+            if src_alpha <= 0.0 {
+                continue;
+            }
+
+            let dest_pixel = frame.get_pixel(fx as u32, fy as u32);
+            let dest_alpha = dest_pixel[3] as f32 / 255.0;
+
+            let out_alpha = src_alpha + dest_alpha * (1.0 - src_alpha);
+            if out_alpha <= 0.0 {
+                continue;
+            }
+
+            let r = (pixel[0] as f32 * src_alpha + dest_pixel[0] as f32 * dest_alpha * (1.0 - src_alpha)) / out_alpha;
+            let g = (pixel[1] as f32 * src_alpha + dest_pixel[1] as f32 * dest_alpha * (1.0 - src_alpha)) / out_alpha;
+            let b = (pixel[2] as f32 * src_alpha + dest_pixel[2] as f32 * dest_alpha * (1.0 - src_alpha)) / out_alpha;
+
+            frame.put_pixel(fx as u32, fy as u32, Rgba([r as u8, g as u8, b as u8, (out_alpha * 255.0) as u8]));
+        }
+    }
+
     /// Composite centered on frame
     pub fn composite_centered(&self, frame: &mut RgbaImage, layer: &RgbaImage) {
         let x = (self.width as i32 - layer.width() as i32) / 2;
@@ -121,6 +303,106 @@ impl FrameComposer {
             (c1[3] as f32 + (c2[3] as f32 - c1[3] as f32) * t) as u8,
         ])
     }
+
+    /// Bright-pass + separable Gaussian blur + additive recombine, so
+    /// dramatic backgrounds get real glow instead of the ad-hoc additive
+    /// circles `Particles`/`Glow` draw per-sprite. A thin wrapper over
+    /// `bloom::Bloom::apply`, which already implements this exact
+    /// pipeline; `radius` derives a matching sigma (`radius / 3`, the usual
+    /// "three sigma spans the kernel" rule of thumb).
+    pub fn bloom(img: &RgbaImage, threshold: f32, radius: u32, intensity: f32) -> RgbaImage {
+        let sigma = (radius as f32 / 3.0).max(0.5);
+        crate::bloom::Bloom::apply(img, threshold, radius, sigma, intensity)
+    }
+
+    /// Fill a frame-sized image from an arbitrary multi-stop `Brush`
+    /// (linear, radial, or conic), so a scene can reach for a richer ramp
+    /// than `create_gradient_frame`'s fixed two-color top-to-bottom blend
+    /// without hand-rolling its own per-pixel loop.
+    pub fn fill_gradient(&self, brush: &Brush) -> RgbaImage {
+        brush.fill(self.width, self.height)
+    }
+
+    /// Composite `layer` onto `frame`, both already premultiplied, with the
+    /// cheap `out = src + dst * (1 - src_a)` accumulation -- no per-pixel
+    /// division, unlike `composite_with_alpha`. Chained composites (card +
+    /// glow + background) should stay premultiplied across every
+    /// intermediate step and only `to_unpremultiplied` once at the end, so
+    /// rounding error from repeated premultiply/un-premultiply round trips
+    /// doesn't accumulate.
+    pub fn composite_premultiplied(&self, frame: &mut RgbaImage, layer: &RgbaImage, x: i32, y: i32) {
+        for (lx, ly, pixel) in layer.enumerate_pixels() {
+            let fx = x + lx as i32;
+            let fy = y + ly as i32;
+
+            if fx < 0 || fy < 0 || fx as u32 >= frame.width() || fy as u32 >= frame.height() {
+                continue;
+            }
+
+            let src_a = pixel[3] as f32 / 255.0;
+            let dest = frame.get_pixel(fx as u32, fy as u32);
+            let inv = 1.0 - src_a;
+
+            let out = Rgba([
+                (pixel[0] as f32 + dest[0] as f32 * inv).min(255.0) as u8,
+                (pixel[1] as f32 + dest[1] as f32 * inv).min(255.0) as u8,
+                (pixel[2] as f32 + dest[2] as f32 * inv).min(255.0) as u8,
+                (pixel[3] as f32 + dest[3] as f32 * inv).min(255.0) as u8,
+            ]);
+            frame.put_pixel(fx as u32, fy as u32, out);
+        }
+    }
+
+    /// Convert a straight-alpha image to premultiplied (`r *= a` etc.).
+    pub fn from_unpremultiplied(img: &RgbaImage) -> RgbaImage {
+        let mut out = img.clone();
+        for pixel in out.pixels_mut() {
+            let a = pixel[3] as f32 / 255.0;
+            pixel[0] = (pixel[0] as f32 * a) as u8;
+            pixel[1] = (pixel[1] as f32 * a) as u8;
+            pixel[2] = (pixel[2] as f32 * a) as u8;
+        }
+        out
+    }
+
+    /// Convert a premultiplied image back to straight alpha (`r /= a` etc.),
+    /// for the final hand-off to the encoder.
+    pub fn to_unpremultiplied(img: &RgbaImage) -> RgbaImage {
+        let mut out = img.clone();
+        for pixel in out.pixels_mut() {
+            let a = pixel[3] as f32 / 255.0;
+            if a <= 0.0 {
+                pixel[0] = 0;
+                pixel[1] = 0;
+                pixel[2] = 0;
+                continue;
+            }
+            pixel[0] = (pixel[0] as f32 / a).min(255.0) as u8;
+            pixel[1] = (pixel[1] as f32 / a).min(255.0) as u8;
+            pixel[2] = (pixel[2] as f32 / a).min(255.0) as u8;
+        }
+        out
+    }
+}
+
+/// Phosphor-trail accumulation for animated backgrounds, e.g.
+/// `Backgrounds::chaos`/`dramatic_dark`. A thin wrapper over
+/// `afterglow::Afterglow`, which already implements the lerp-towards-history
+/// with a small leak and a near-black reset this request describes -- there
+/// is no need for a second copy of that formula here.
+pub struct FrameAccumulator {
+    afterglow: crate::afterglow::Afterglow,
+}
+
+impl FrameAccumulator {
+    pub fn new(persistence: f32) -> Self {
+        Self { afterglow: crate::afterglow::Afterglow::new(crate::afterglow::Persistence::uniform(persistence)) }
+    }
+
+    /// Blend `new_frame` into the trailing history and return the result.
+    pub fn accumulate(&mut self, new_frame: &RgbaImage) -> RgbaImage {
+        self.afterglow.feed(new_frame)
+    }
 }
 
 /// Background patterns and effects
@@ -0,0 +1,97 @@
+//! Centralized deterministic pseudo-random source
+//!
+//! `Particles::sparkles(..., 42)` and `Backgrounds::chaos(..., 42)` each
+//! took ad-hoc seed literals, so there was no single reproducibility
+//! guarantee across a render and no way to vary a whole video with one
+//! master seed. `XorShift` is a small, dependency-free RNG `SceneManager`
+//! owns and seeds once from a top-level seed; scene renderers derive
+//! per-effect sub-streams with `derive`, mixing the master state with a
+//! salt unique to that call site, so two renders of the same seed are
+//! bit-identical but a different master seed reshuffles every sparkle,
+//! wave, and chaos-background offset.
+
+/// A 64-bit xorshift generator (Marsaglia's shift-xor-shift).
+#[derive(Debug, Clone, Copy)]
+pub struct XorShift {
+    state: u64,
+}
+
+impl XorShift {
+    /// Seed the stream. `0` would get stuck at `0` forever, so it's
+    /// remapped to a fixed non-zero constant instead.
+    pub fn new(seed: u64) -> Self {
+        Self { state: if seed == 0 { 0x9E3779B97F4A7C15 } else { seed } }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    /// A uniform float in `[0, 1)`.
+    pub fn next_f32(&mut self) -> f32 {
+        (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + self.next_f32() * (max - min)
+    }
+
+    /// Derive an independent, deterministic sub-stream by mixing this
+    /// stream's current state with `salt`, e.g. `seed ^
+    /// scene_index.wrapping_mul(0x9E3779B9)`.
+    pub fn derive(&self, salt: u64) -> Self {
+        Self::new(self.state ^ salt.wrapping_mul(0x9E3779B9))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_produces_a_bit_identical_stream() {
+        let mut a = XorShift::new(1234);
+        let mut b = XorShift::new(1234);
+        for _ in 0..10 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[test]
+    fn different_seeds_diverge() {
+        let mut a = XorShift::new(1);
+        let mut b = XorShift::new(2);
+        assert_ne!(a.next_u64(), b.next_u64());
+    }
+
+    #[test]
+    fn zero_seed_is_remapped_away_from_the_stuck_state() {
+        let mut rng = XorShift::new(0);
+        assert_ne!(rng.next_u64(), 0);
+    }
+
+    #[test]
+    fn next_f32_stays_within_zero_one() {
+        let mut rng = XorShift::new(42);
+        for _ in 0..100 {
+            let f = rng.next_f32();
+            assert!((0.0..1.0).contains(&f), "{f} out of range");
+        }
+    }
+
+    #[test]
+    fn derive_is_deterministic_and_salt_sensitive() {
+        let base = XorShift::new(99);
+        let mut d1 = base.derive(7);
+        let mut d2 = base.derive(7);
+        let mut d3 = base.derive(8);
+
+        assert_eq!(d1.next_u64(), d2.next_u64());
+        assert_ne!(base.derive(7).next_u64(), d3.next_u64());
+    }
+}